@@ -0,0 +1,55 @@
+#!/usr/bin/env python3
+"""Regenerates src/formats/oui.tsv from the public IEEE MA-L, MA-M and MA-S registries.
+
+Usage: scripts/update_oui.py [directory with oui.csv, mam.csv and oui36.csv]
+
+Without a directory, the CSV files are downloaded from https://standards-oui.ieee.org/.
+"""
+
+import csv
+import io
+import pathlib
+import sys
+import urllib.request
+
+REGISTRIES = {
+    "oui.csv": ("MA-L", 6),
+    "mam.csv": ("MA-M", 7),
+    "oui36.csv": ("MA-S", 9),
+}
+BASE_URL = "https://standards-oui.ieee.org/"
+OUTPUT = pathlib.Path(__file__).resolve().parent.parent / "src" / "formats" / "oui.tsv"
+HEADER = """\
+# IEEE MAC address registries (MA-L, MA-M and MA-S), generated by scripts/update_oui.py.
+# One assignment per line: the prefix as 6 (MA-L), 7 (MA-M) or 9 (MA-S) uppercase hex digits, a tab, and the organization.
+"""
+
+
+def read_registry(name: str, source: pathlib.Path | None) -> str:
+    if source is not None:
+        return (source / name).read_text(encoding="utf-8")
+    request = urllib.request.Request(BASE_URL + name, headers={"User-Agent": "uuinfo registry update"})
+    with urllib.request.urlopen(request) as response:
+        return response.read().decode("utf-8")
+
+
+def main() -> None:
+    source = pathlib.Path(sys.argv[1]) if len(sys.argv) > 1 else None
+    assignments = {}
+    for name, (registry, digits) in REGISTRIES.items():
+        rows = csv.DictReader(io.StringIO(read_registry(name, source)))
+        for row in rows:
+            if row["Registry"] != registry:
+                continue
+            prefix = row["Assignment"].strip().upper()
+            organization = " ".join(row["Organization Name"].split())
+            if len(prefix) != digits or not organization:
+                continue
+            assignments[prefix] = organization
+    lines = [f"{prefix}\t{organization}\n" for prefix, organization in sorted(assignments.items())]
+    OUTPUT.write_text(HEADER + "".join(lines), encoding="utf-8")
+    print(f"Wrote {len(lines)} assignments to {OUTPUT}")
+
+
+if __name__ == "__main__":
+    main()
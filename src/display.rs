@@ -4,30 +4,29 @@ use std::io::{Write, stdout};
 use chrono::Utc;
 use clap::ValueEnum;
 use colored::*;
+use terminal_size::{Width, terminal_size};
 use timediff::TimeDiff;
 
 use crate::schema::{Args, IDInfo, Output};
+use crate::utils::localize_datetime;
 
-fn truncate_to_millis(ts: &str) -> &str {
-    match ts.find('.') {
-        Some(dot) => {
-            let end = (dot + 4).min(ts.len());
-            &ts[..end]
-        }
-        None => ts,
+/// Drops trailing groups of three zeros from the sub-second digits, keeping at least milliseconds.
+fn significant_fraction(digits: &str) -> &str {
+    let mut end = digits.len();
+    while end > 3 && digits.get(end - 3..end) == Some("000") {
+        end -= 3;
     }
+    digits.get(..end.min(digits.len())).unwrap_or(digits)
 }
 
-fn truncate_datetime_to_millis(dt: &str) -> String {
-    if let Some(dot) = dt.find('.') {
-        let after_dot = &dt[dot + 1..];
-        let non_digit_pos = after_dot.find(|c: char| !c.is_ascii_digit()).unwrap_or(after_dot.len());
-        let digits = &after_dot[..non_digit_pos];
-        let suffix = &after_dot[non_digit_pos..];
-        let millis: &str = if digits.len() >= 3 { &digits[..3] } else { digits };
-        format!("{}.{}{}", &dt[..dot], millis, suffix)
-    } else {
-        dt.to_string()
+fn trim_fraction(value: &str) -> String {
+    match value.split_once('.') {
+        Some((whole, after_dot)) => {
+            let non_digit_pos = after_dot.find(|c: char| !c.is_ascii_digit()).unwrap_or(after_dot.len());
+            let (digits, suffix) = after_dot.split_at(non_digit_pos);
+            format!("{}.{}{}", whole, significant_fraction(digits), suffix)
+        }
+        None => value.to_string(),
     }
 }
 
@@ -37,29 +36,83 @@ impl std::fmt::Display for Output {
     }
 }
 
+const DEFAULT_WIDTH: usize = 80;
+const LABEL_SPACE: usize = 9;
+const BORDERS_SPACE: usize = 7; // "┃ " + " │ " + " ┃"
+const COMPACT_INDENT: usize = 2;
+
+#[derive(Debug, PartialEq)]
+struct CardLayout {
+    compact: bool,
+    grid_bits: usize,
+    width: usize,
+}
+
+impl CardLayout {
+    fn from_width(width: usize) -> CardLayout {
+        let (wide_hex, wide_bin) = grid_width(64);
+        let (_, classic_bin) = grid_width(32);
+        if width >= wide_hex + wide_bin + BORDERS_SPACE {
+            CardLayout { compact: false, grid_bits: 64, width }
+        } else if width >= LABEL_SPACE + classic_bin + BORDERS_SPACE {
+            CardLayout { compact: false, grid_bits: 32, width }
+        } else {
+            CardLayout { compact: true, grid_bits: 16, width }
+        }
+    }
+
+    fn l_space(&self) -> usize {
+        cmp::max(LABEL_SPACE, grid_width(self.grid_bits).0)
+    }
+}
+
+enum CardRow {
+    Field(ColoredString, String),
+    Divider,
+}
+
+/// Visible width of the hex and binary columns of a grid line with `bits` bits.
+fn grid_width(bits: usize) -> (usize, usize) {
+    let groups = bits / 16;
+    (groups * 5 - 1, groups * 23 - 3)
+}
+
+fn terminal_width() -> usize {
+    match terminal_size() {
+        Some((Width(width), _)) => width.into(),
+        None => std::env::var("COLUMNS").ok().and_then(|value| value.parse().ok()).unwrap_or(DEFAULT_WIDTH),
+    }
+}
+
+/// Splits the text in lines of at most `width` chars, breaking on spaces when possible.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = cmp::max(width, 1);
+    let mut lines: Vec<String> = vec![];
+    let mut rest: Vec<char> = text.chars().collect();
+    while rest.len() > width {
+        let split = match rest.iter().take(width + 1).rposition(|c| *c == ' ') {
+            Some(0) | None => width,
+            Some(position) => position,
+        };
+        let (line, tail) = rest.split_at(split);
+        lines.push(line.iter().collect::<String>().trim_end().to_string());
+        rest = tail.iter().skip_while(|c| **c == ' ').copied().collect();
+    }
+    lines.push(rest.into_iter().collect());
+    lines
+}
+
 impl IDInfo {
-    #[allow(clippy::indexing_slicing)]
-    pub fn print_card(&self, args: &Args) {
+    fn card_rows(&self, args: &Args) -> Vec<CardRow> {
         let timestamp = match self.timestamp.as_deref() {
             Some(value) => {
-                let ts_display = truncate_to_millis(value);
-                let dt_display = truncate_datetime_to_millis(self.datetime.as_deref().unwrap_or("-"));
+                let ts_display = trim_fraction(value);
+                let dt_display = trim_fraction(self.datetime_tz.as_deref().or(self.datetime.as_deref()).unwrap_or("-"));
                 format!("{} ({})", ts_display, dt_display)
             }
             None => "-".to_string(),
         };
 
-        const MIN_R_SPACE: usize = 43;
-        let l_space = 9;
-        let r_space = cmp::max(MIN_R_SPACE, timestamp.chars().count());
-
-        fn limit_r(text: String) -> String {
-            match text.char_indices().nth(MIN_R_SPACE) {
-                None => text,
-                Some((idx, _)) => format!("{}...", &text[..idx - 3]),
-            }
-        }
-
         let size = match self.size {
             0 => "-".to_string(),
             _ => match &self.parsed {
@@ -73,60 +126,124 @@ impl IDInfo {
             _ => format!("{} bits", self.entropy),
         };
 
-        println!("┏━{:━<l_space$}━┯{:━<r_space$}━━┓", "", "");
-        println!("┃ {:<l_space$} │ {:<r_space$} ┃", "ID Type", self.id_type);
-        println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Version".yellow(), self.version.as_deref().unwrap_or("-"));
-        println!("┠─{:─<l_space$}─┼─{:─<r_space$}─┨", "", "");
-        println!("┃ {:<l_space$} │ {:<r_space$} ┃", "String", limit_r(self.standard.clone()));
-
-        if let Some(value) = self.integer {
-            println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Integer", value);
-        }
-        if let Some(value) = self.uuid_wrap.as_deref() {
-            println!("┃ {:<l_space$} │ {:<r_space$} ┃", "UUID wrap", value);
-        }
         let sequence = match self.sequence {
             Some(value) => value.to_string(),
             None => "-".to_string(),
         };
-        println!("┠─{:─<l_space$}─┼─{:─<r_space$}─┨", "", "");
-        println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Size", size);
-        println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Entropy".green(), entropy);
-        println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Timestamp".cyan(), timestamp);
 
+        let mut rows: Vec<CardRow> = vec![
+            CardRow::Field("ID Type".normal(), self.id_type.clone()),
+            CardRow::Field("Version".yellow(), self.version.clone().unwrap_or("-".to_string())),
+            CardRow::Divider,
+            CardRow::Field("String".normal(), self.standard.clone()),
+        ];
+        if let Some(value) = self.integer {
+            rows.push(CardRow::Field("Integer".normal(), value.to_string()));
+        }
+        if let Some(value) = self.uuid_wrap.clone() {
+            rows.push(CardRow::Field("UUID wrap".normal(), value));
+        }
+        rows.push(CardRow::Divider);
+        rows.push(CardRow::Field("Size".normal(), size));
+        rows.push(CardRow::Field("Entropy".green(), entropy));
+        rows.push(CardRow::Field("Timestamp".cyan(), timestamp));
         if let Some(value) = self.relative_time.clone()
             && args.relative
         {
-            println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Relative".cyan(), value);
+            rows.push(CardRow::Field("Relative".cyan(), value));
         }
-
-        println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Node 1".purple(), limit_r(self.node1.clone().unwrap_or("-".to_string())));
-        println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Node 2".red(), limit_r(self.node2.clone().unwrap_or("-".to_string())));
+        rows.push(CardRow::Field("Node 1".purple(), self.node1.clone().unwrap_or("-".to_string())));
+        rows.push(CardRow::Field("Node 2".red(), self.node2.clone().unwrap_or("-".to_string())));
         if let Some(value) = self.node3.clone() {
-            println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Node 3".custom_color((208, 135, 112)), limit_r(value));
+            rows.push(CardRow::Field("Node 3".custom_color((208, 135, 112)), value));
+        }
+        rows.push(CardRow::Field("Sequence".blue(), sequence));
+        rows
+    }
+
+    pub fn print_card(&self, args: &Args) {
+        let layout = CardLayout::from_width(terminal_width());
+        let rows = self.card_rows(args);
+        let (hex_lines, bin_lines) = self.get_hex_bin_lines(layout.grid_bits);
+        if layout.compact {
+            self.print_card_compact(&layout, rows, hex_lines, bin_lines);
+        } else {
+            self.print_card_boxed(&layout, rows, hex_lines, bin_lines);
+        }
+    }
+
+    fn print_card_boxed(&self, layout: &CardLayout, rows: Vec<CardRow>, hex_lines: Vec<String>, bin_lines: Vec<String>) {
+        let l_space = layout.l_space();
+        let grid_space = grid_width(layout.grid_bits).1;
+        let longest = rows
+            .iter()
+            .map(|row| match row {
+                CardRow::Field(_, value) => value.chars().count(),
+                CardRow::Divider => 0,
+            })
+            .max()
+            .unwrap_or(0);
+        let max_r_space = cmp::max(grid_space, layout.width.saturating_sub(l_space + BORDERS_SPACE));
+        let r_space = longest.clamp(grid_space, max_r_space);
+
+        println!("┏━{:━<l_space$}━┯{:━<r_space$}━━┓", "", "");
+        for row in rows {
+            match row {
+                CardRow::Field(label, value) => {
+                    for (i, line) in wrap_text(&value, r_space).into_iter().enumerate() {
+                        if i == 0 {
+                            println!("┃ {:<l_space$} │ {:<r_space$} ┃", label, line);
+                        } else {
+                            println!("┃ {:<l_space$} │ {:<r_space$} ┃", "", line);
+                        }
+                    }
+                }
+                CardRow::Divider => println!("┠─{:─<l_space$}─┼─{:─<r_space$}─┨", "", ""),
+            }
         }
-        println!("┃ {:<l_space$} │ {:<r_space$} ┃", "Sequence".blue(), sequence);
         println!("┠─{:─<l_space$}─┼─{:─<r_space$}─┨", "", "");
 
-        let (hex_lines, bin_lines) = self.get_hex_bin_lines();
-        let fix_space = r_space - MIN_R_SPACE; // The colored rendering messes with the count.
-        for (i, hex_line) in hex_lines.into_iter().enumerate() {
+        let fix_space = r_space - grid_space; // The colored rendering messes with the count.
+        for (hex_line, bin_line) in hex_lines.into_iter().zip(bin_lines) {
             if self.bits.is_some() {
-                println!("┃ {:<l_space$} │ {}{:<fix_space$} ┃", hex_line, bin_lines[i], "");
+                println!("┃ {:<l_space$} │ {}{:<fix_space$} ┃", hex_line, bin_line, "");
             } else {
-                println!("┃ {:<l_space$} │ {:<r_space$} ┃", hex_line, bin_lines[i]);
+                println!("┃ {:<l_space$} │ {:<r_space$} ┃", hex_line, bin_line);
             }
         }
         println!("┗━{:━<l_space$}━┷{:━<r_space$}━━┛", "", "");
     }
 
-    fn get_hex_bin_lines(&self) -> (Vec<String>, Vec<String>) {
+    fn print_card_compact(&self, layout: &CardLayout, rows: Vec<CardRow>, hex_lines: Vec<String>, bin_lines: Vec<String>) {
+        let indent = " ".repeat(COMPACT_INDENT);
+        let value_space = layout.width.saturating_sub(COMPACT_INDENT);
+
+        println!("{:━<width$}", "", width = layout.width);
+        for row in rows {
+            match row {
+                CardRow::Field(label, value) => {
+                    println!("{}", label);
+                    for line in wrap_text(&value, value_space) {
+                        println!("{}{}", indent, line);
+                    }
+                }
+                CardRow::Divider => println!("{:─<width$}", "", width = layout.width),
+            }
+        }
+        println!("{:─<width$}", "", width = layout.width);
+        for (hex_line, bin_line) in hex_lines.into_iter().zip(bin_lines) {
+            println!("{}{}{}", hex_line, indent, bin_line);
+        }
+        println!("{:━<width$}", "", width = layout.width);
+    }
+
+    fn get_hex_bin_lines(&self, bits_per_line: usize) -> (Vec<String>, Vec<String>) {
         let mut bin_lines: Vec<String> = vec![];
         let mut hex_lines: Vec<String> = vec![];
 
         match (&self.bits, &self.color_map, &self.hex) {
             (Some(bits), Some(color_map), Some(hex)) => {
-                let remaining_bits = (32 - (bits.chars().count() % 32)) % 32;
+                let remaining_bits = (bits_per_line - (bits.chars().count() % bits_per_line)) % bits_per_line;
                 let padded_bits: String = format!("{}{}", bits, ".".repeat(remaining_bits));
                 let padded_color_map: String = format!("{}{}", color_map, "0".repeat(remaining_bits));
                 let padded_hex: String = format!("{}{}", hex, ".".repeat(remaining_bits / 4));
@@ -161,7 +278,7 @@ impl IDInfo {
                         bin_line.push(' ');
                         hex_line.push(' ');
                     }
-                    if ((i + 1) % 32) == 0 {
+                    if ((i + 1) % bits_per_line) == 0 {
                         bin_lines.push(bin_line.trim().to_string());
                         hex_lines.push(hex_line.trim().to_string());
                         bin_line = String::new();
@@ -219,6 +336,9 @@ impl IDInfo {
             let diff = timestamp_sec - Utc::now().timestamp();
             self.relative_time = Some(TimeDiff::to_diff((diff).to_string() + "s").parse().unwrap_or('-'.to_string()));
         }
+        if let (Some(tz), Some(datetime)) = (&args.tz, &self.datetime) {
+            self.datetime_tz = Some(localize_datetime(datetime, tz));
+        }
         if args.everything {
             self.print_card(args);
         } else {
@@ -231,3 +351,88 @@ impl IDInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::repeat_char;
+
+    #[test]
+    fn test_layout_from_width() {
+        assert_eq!(
+            CardLayout::from_width(40),
+            CardLayout {
+                compact: true,
+                grid_bits: 16,
+                width: 40
+            }
+        );
+        assert_eq!(
+            CardLayout::from_width(59),
+            CardLayout {
+                compact: false,
+                grid_bits: 32,
+                width: 59
+            }
+        );
+        assert_eq!(
+            CardLayout::from_width(114),
+            CardLayout {
+                compact: false,
+                grid_bits: 32,
+                width: 114
+            }
+        );
+        assert_eq!(
+            CardLayout::from_width(115),
+            CardLayout {
+                compact: false,
+                grid_bits: 64,
+                width: 115
+            }
+        );
+        assert_eq!(CardLayout::from_width(115).l_space(), 19);
+        assert_eq!(CardLayout::from_width(80).l_space(), 9);
+    }
+
+    #[test]
+    fn test_grid_width() {
+        assert_eq!(grid_width(16), (4, 20));
+        assert_eq!(grid_width(32), (9, 43));
+        assert_eq!(grid_width(64), (19, 89));
+    }
+
+    #[test]
+    fn test_trim_fraction() {
+        assert_eq!(trim_fraction("1700000000.000000000"), "1700000000.000");
+        assert_eq!(trim_fraction("1700000000.123456000"), "1700000000.123456");
+        assert_eq!(trim_fraction("-1.500000000"), "-1.500");
+        assert_eq!(trim_fraction("2023-11-14T22:13:20.123456789Z"), "2023-11-14T22:13:20.123456789Z");
+        assert_eq!(trim_fraction("2023-11-14T23:13:20.120000000+01:00"), "2023-11-14T23:13:20.120+01:00");
+        assert_eq!(trim_fraction("2023-11-14T22:13:20.5Z"), "2023-11-14T22:13:20.5Z");
+        assert_eq!(trim_fraction("2023-11-14T22:13:20Z"), "2023-11-14T22:13:20Z");
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        assert_eq!(wrap_text("short", 10), vec!["short"]);
+        assert_eq!(wrap_text("0123456789abcdef", 10), vec!["0123456789", "abcdef"]);
+        assert_eq!(wrap_text("1735689600.000 (2025-01-01T00:00:00.000Z)", 30), vec!["1735689600.000", "(2025-01-01T00:00:00.000Z)"]);
+        assert_eq!(wrap_text("", 10), vec![""]);
+    }
+
+    #[test]
+    fn test_hex_bin_lines_64_bits() {
+        let id_info = IDInfo {
+            hex: Some("00000000ffffffff".to_string()),
+            bits: Some(repeat_char('0', 32) + &repeat_char('1', 32)),
+            color_map: Some(repeat_char('0', 64)),
+            ..Default::default()
+        };
+        let (hex_lines, bin_lines) = id_info.get_hex_bin_lines(64);
+        assert_eq!(hex_lines, vec!["0000 0000 ffff ffff"]);
+        assert_eq!(bin_lines.len(), 1);
+        let (hex_lines, _) = id_info.get_hex_bin_lines(32);
+        assert_eq!(hex_lines, vec!["0000 0000", "ffff ffff"]);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::country::country_name;
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::{factor_size_hex_bits_color_from_text, repeat_char};
+
+fn federal_reserve_district(district: u32) -> Option<&'static str> {
+    let name = match district {
+        1 => "Boston",
+        2 => "New York",
+        3 => "Philadelphia",
+        4 => "Cleveland",
+        5 => "Richmond",
+        6 => "Atlanta",
+        7 => "Chicago",
+        8 => "St. Louis",
+        9 => "Minneapolis",
+        10 => "Kansas City",
+        11 => "Dallas",
+        12 => "San Francisco",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// Banks by the first two digits of their sort codes, as historically allocated.
+fn sort_code_bank(prefix: &str) -> Option<&'static str> {
+    let bank = match prefix {
+        "07" => "Nationwide Building Society",
+        "09" => "Santander UK",
+        "11" => "Halifax",
+        "16" => "Royal Bank of Scotland",
+        "20" => "Barclays",
+        "30" => "Lloyds Bank",
+        "40" => "HSBC UK",
+        "60" => "NatWest",
+        "80" => "Bank of Scotland",
+        "82" => "Clydesdale Bank",
+        "90" => "Bank of Ireland (Northern Ireland)",
+        "93" => "AIB (Northern Ireland)",
+        "95" => "Danske Bank (Northern Ireland)",
+        "98" => "Ulster Bank (Northern Ireland)",
+        _ => return None,
+    };
+    Some(bank)
+}
+
+/// BIC (ISO 9362, also known as SWIFT code): institution, country, location and an optional branch.
+pub fn parse_bic(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if !(id.len() == 8 || id.len() == 11) || !id.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()) {
+        return None;
+    }
+    let institution = id.get(0..4)?;
+    let country = id.get(4..6)?;
+    let location = id.get(6..8)?;
+    let branch = id.get(8..).filter(|branch| !branch.is_empty());
+    if !country.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    // SWIFT assigns BICs in Kosovo under the user-assigned code "XK":
+    let name = if country == "XK" { "Kosovo" } else { country_name(country) };
+    if name == "Unknown" && args.force != Some(IdFormat::Bic) {
+        return None;
+    }
+    // Hex numbers like "DEADBEEF" or "CAFEBABE" also fit the pattern, so they're only read as BICs when forced:
+    if id.chars().all(|c| c.is_ascii_hexdigit()) && args.force != Some(IdFormat::Bic) {
+        return None;
+    }
+    // The second character of the location code marks test and non-connected BICs:
+    let version = match location.get(1..2)? {
+        "0" => "Test BIC",
+        "1" => "Passive participant (not connected to SWIFT)",
+        "2" => "Reverse billing",
+        _ => "Production BIC",
+    };
+    let branch_text = match branch {
+        Some("XXX") | None => "XXX (Primary office)".to_string(),
+        Some(branch) => format!("{} (Branch)", branch),
+    };
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+
+    Some(IDInfo {
+        id_type: "BIC (SWIFT code)".to_string(),
+        version: Some(version.to_string()),
+        standard: id.to_string(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("{} (Institution)", institution)),
+        node2: Some(format!("{} ({}), {} (Location)", country, name, location)),
+        node3: Some(branch_text),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 32) + &repeat_char('1', 16) + &repeat_char('5', 16) + &repeat_char('7', branch.map_or(0, str::len) * 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// ABA routing transit number: Federal Reserve routing symbol, institution and a weighted (3, 7, 1) check digit.
+/// Not auto-detected, as any 9-digit number could be one.
+pub fn parse_aba(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if id.len() != 9 || !id.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut sum = 0;
+    for (c, weight) in id.chars().zip([3, 7, 1, 3, 7, 1, 3, 7, 1]) {
+        sum += c.to_digit(10)? * weight;
+    }
+    if sum % 10 != 0 {
+        return None;
+    }
+    let prefix = id.get(0..2)?.parse::<u32>().ok()?;
+    let (district, version) = match prefix {
+        0 => (None, "United States Government"),
+        1..=12 => (Some(prefix), "Primary"),
+        21..=32 => (Some(prefix - 20), "Thrift institution"),
+        61..=72 => (Some(prefix - 60), "Electronic transaction"),
+        80 => (None, "Traveler's cheque"),
+        _ => return None,
+    };
+    let node1 = match district.and_then(|district| Some((district, federal_reserve_district(district)?))) {
+        Some((district, name)) => format!("{} (Federal Reserve district {}: {})", id.get(0..2)?, district, name),
+        None => format!("{} (Federal Reserve routing symbol)", id.get(0..2)?),
+    };
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+
+    Some(IDInfo {
+        id_type: "ABA Routing Number".to_string(),
+        version: Some(version.to_string()),
+        standard: id.to_string(),
+        integer: id.parse::<u128>().ok(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(node1),
+        node2: Some(format!("{} (Federal Reserve check processing center)", id.get(2..4)?)),
+        node3: Some(format!("{} (Check Digit)", id.get(8..9)?)),
+        sequence: id.get(4..8)?.parse::<u128>().ok(),
+        hex,
+        bits,
+        color_map: Some(repeat_char('1', 16) + &repeat_char('4', 16) + &repeat_char('6', 32) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// UK sort code: 6 digits identifying a bank and branch, auto-detected only as "12-34-56".
+pub fn parse_sort_code(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    let dashed = id.split('-').map(str::len).eq([2, 2, 2]);
+    let digits = id.replace("-", "");
+    if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_digit()) || !(dashed || args.force == Some(IdFormat::SortCode)) {
+        return None;
+    }
+    let prefix = digits.get(0..2)?;
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&digits);
+
+    Some(IDInfo {
+        id_type: "UK Sort Code".to_string(),
+        standard: format!("{}-{}-{}", prefix, digits.get(2..4)?, digits.get(4..6)?),
+        integer: digits.parse::<u128>().ok(),
+        parsed: Some("as ASCII, no dashes".to_string()),
+        size,
+        node1: Some(match sort_code_bank(prefix) {
+            Some(bank) => format!("{} (Bank: {})", prefix, bank),
+            None => format!("{} (Bank)", prefix),
+        }),
+        node2: Some(format!("{} (Branch)", digits.get(2..6)?)),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 16) + &repeat_char('5', 32)),
+        high_confidence: dashed,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(id: &str) -> Args {
+        Args {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_bic() {
+        let id_info = parse_bic(&args("DEUTDEFF")).unwrap();
+        assert_eq!(id_info.version.unwrap(), "Production BIC");
+        assert_eq!(id_info.node1.unwrap(), "DEUT (Institution)");
+        assert_eq!(id_info.node2.unwrap(), "DE (Germany), FF (Location)");
+        assert_eq!(id_info.node3.unwrap(), "XXX (Primary office)");
+        let id_info = parse_bic(&args("NWBKGB2L100")).unwrap();
+        assert_eq!(id_info.node3.unwrap(), "100 (Branch)");
+        assert_eq!(parse_bic(&args("DEUTDEF0")).unwrap().version.unwrap(), "Test BIC");
+        assert!(parse_bic(&args("ABCDEFGH")).is_none());
+        assert!(parse_bic(&args("deutdeff")).is_none());
+        assert!(parse_bic(&args("DEADBEEF")).is_none());
+        let id_info = parse_bic(&Args {
+            force: Some(IdFormat::Bic),
+            ..args("CAFEBABE")
+        });
+        assert_eq!(id_info.unwrap().node2.unwrap(), "BA (Bosnia and Herzegovina), BE (Location)");
+    }
+
+    #[test]
+    fn test_parse_aba() {
+        let id_info = parse_aba(&args("021000021")).unwrap();
+        assert_eq!(id_info.version.unwrap(), "Primary");
+        assert_eq!(id_info.node1.unwrap(), "02 (Federal Reserve district 2: New York)");
+        let id_info = parse_aba(&args("322271627")).unwrap();
+        assert_eq!(id_info.version.unwrap(), "Thrift institution");
+        assert_eq!(id_info.node1.unwrap(), "32 (Federal Reserve district 12: San Francisco)");
+        assert!(parse_aba(&args("021000022")).is_none());
+    }
+
+    #[test]
+    fn test_parse_sort_code() {
+        let id_info = parse_sort_code(&args("20-00-00")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "20 (Bank: Barclays)");
+        assert!(parse_sort_code(&args("200000")).is_none());
+        assert_eq!(
+            parse_sort_code(&Args {
+                force: Some(IdFormat::SortCode),
+                ..args("200000")
+            })
+            .unwrap()
+            .standard,
+            "20-00-00"
+        );
+    }
+}
@@ -159,6 +159,15 @@ mod tests {
             relative: false,
             salt: None,
             epoch: None,
+            time_unit: None,
+            tz: None,
+            near: None,
+            between: None,
+            namespace: None,
+            name: None,
+            names_from: None,
+            reveal: false,
+            now: None,
         }
     }
 
@@ -0,0 +1,190 @@
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::{factor_size_hex_bits_color_from_text, luhn_check_digit, repeat_char};
+
+/// Issuer Identification Number ranges: lowest and highest prefix (same number of digits), network and valid lengths.
+const CARD_NETWORKS: &[(&str, &str, &str, &[usize])] = &[
+    ("4", "4", "Visa", &[13, 16, 19]),
+    ("51", "55", "Mastercard", &[16]),
+    ("2221", "2720", "Mastercard", &[16]),
+    ("34", "34", "American Express", &[15]),
+    ("37", "37", "American Express", &[15]),
+    ("6011", "6011", "Discover", &[16, 17, 18, 19]),
+    ("644", "649", "Discover", &[16, 17, 18, 19]),
+    ("65", "65", "Discover", &[16, 17, 18, 19]),
+    ("3528", "3589", "JCB", &[16, 17, 18, 19]),
+    ("62", "62", "UnionPay", &[16, 17, 18, 19]),
+    ("36", "36", "Diners Club International", &[14, 15, 16, 17, 18, 19]),
+    ("300", "305", "Diners Club International", &[14, 15, 16, 17, 18, 19]),
+    ("3095", "3095", "Diners Club International", &[14, 15, 16, 17, 18, 19]),
+    ("38", "39", "Diners Club International", &[14, 15, 16, 17, 18, 19]),
+    ("5018", "5018", "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    ("5020", "5020", "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    ("5038", "5038", "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    ("5893", "5893", "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    ("6304", "6304", "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    ("6759", "6759", "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    ("6761", "6763", "Maestro", &[12, 13, 14, 15, 16, 17, 18, 19]),
+    ("2200", "2204", "Mir", &[16, 17, 18, 19]),
+    ("60", "60", "RuPay", &[16]),
+    ("508", "508", "RuPay", &[16]),
+    ("6521", "6522", "RuPay", &[16]),
+    ("9792", "9792", "Troy", &[16]),
+];
+
+/// Network of the longest matching IIN range, and whether the length is valid for it.
+fn card_network(digits: &str) -> Option<(&'static str, bool)> {
+    CARD_NETWORKS
+        .iter()
+        .filter(|(low, high, _, _)| digits.get(..low.len()).is_some_and(|prefix| *low <= prefix && prefix <= *high))
+        .max_by_key(|(low, _, _, _)| low.len())
+        .map(|(_, _, network, lengths)| (*network, lengths.contains(&digits.len())))
+}
+
+fn major_industry(digits: &str) -> &'static str {
+    match digits.get(..1) {
+        Some("1" | "2") => "Airlines",
+        Some("3") => "Travel and entertainment",
+        Some("4" | "5") => "Banking and financial",
+        Some("6") => "Merchandising and banking",
+        Some("7") => "Petroleum",
+        Some("8") => "Healthcare and telecommunications",
+        Some("9") => "National assignment",
+        _ => "ISO/TC 68",
+    }
+}
+
+/// Groups of 4 digits, or 4-6-5 for 15-digit cards (American Express).
+fn group_digits(digits: &str) -> String {
+    let chars: Vec<char> = digits.chars().collect();
+    let sizes: &[usize] = if chars.len() == 15 { &[4, 6, 5] } else { &[4; 5] };
+    let mut groups = vec![];
+    let mut rest = chars.as_slice();
+    for size in sizes.iter().chain(std::iter::repeat(&4)) {
+        if rest.is_empty() {
+            break;
+        }
+        let (group, tail) = rest.split_at((*size).min(rest.len()));
+        groups.push(group.iter().collect::<String>());
+        rest = tail;
+    }
+    groups.join(" ")
+}
+
+/// Keeps the first 6 and last 4 digits, as allowed by PCI DSS.
+fn mask_digits(digits: &str) -> String {
+    digits.chars().enumerate().map(|(index, c)| if index < 6 || index + 4 >= digits.len() { c } else { '*' }).collect()
+}
+
+/// Payment card numbers (PAN, ISO/IEC 7812) are masked in every output unless `--reveal` is given.
+pub fn parse_pan(args: &Args) -> Option<IDInfo> {
+    let digits: String = args.id.trim().chars().filter(|c| *c != ' ' && *c != '-').collect();
+    if !(12..=19).contains(&digits.len()) || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (payload, check) = digits.split_at(digits.len() - 1);
+    if check.parse::<u32>().ok()? != luhn_check_digit(payload, 10)? {
+        return None;
+    }
+    let network = card_network(&digits);
+    let known = network.is_some_and(|(_, valid_length)| valid_length);
+    if !known && args.force != Some(IdFormat::Pan) {
+        return None;
+    }
+    let version = match network {
+        Some((network, true)) => network.to_string(),
+        Some((network, false)) => format!("{} (invalid length)", network),
+        None => "Unknown network".to_string(),
+    };
+    let account = digits.get(6..digits.len() - 1)?;
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&digits);
+    let mut id_info = IDInfo {
+        id_type: "Payment Card Number".to_string(),
+        version: Some(version),
+        standard: group_digits(&digits),
+        integer: digits.parse::<u128>().ok(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("{} (Issuer Identification Number)", digits.get(..6)?)),
+        node2: Some(format!("{} (Check Digit)", check)),
+        node3: Some(format!("{} (Major Industry Identifier: {})", digits.get(..1)?, major_industry(&digits))),
+        sequence: account.parse::<u128>().ok(),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 48) + &repeat_char('6', account.len() * 8) + &repeat_char('5', 8)),
+        high_confidence: known,
+        ..Default::default()
+    };
+    if !args.reveal {
+        id_info = IDInfo {
+            standard: group_digits(&mask_digits(&digits)),
+            integer: None,
+            parsed: Some("as ASCII, masked (use --reveal to show the full number)".to_string()),
+            sequence: None,
+            hex: None,
+            bits: None,
+            color_map: None,
+            ..id_info
+        };
+    }
+    Some(id_info)
+}
+
+/// A card number that is going to be shown masked, so no other interpretation may echo its digits.
+pub fn is_masked_pan(args: &Args) -> bool {
+    !args.reveal && parse_pan(args).is_some_and(|value| value.high_confidence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(id: &str, reveal: bool, force: Option<IdFormat>) -> Option<IDInfo> {
+        parse_pan(&Args {
+            id: id.to_string(),
+            reveal,
+            force,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_card_network() {
+        assert_eq!(card_network("4111111111111111"), Some(("Visa", true)));
+        assert_eq!(card_network("2221000000000009"), Some(("Mastercard", true)));
+        assert_eq!(card_network("378282246310005"), Some(("American Express", true)));
+        assert_eq!(card_network("6011111111111117"), Some(("Discover", true)));
+        assert_eq!(card_network("3530111333300000"), Some(("JCB", true)));
+        assert_eq!(card_network("6200000000000005"), Some(("UnionPay", true)));
+        assert_eq!(card_network("6759649826438453"), Some(("Maestro", true)));
+        assert_eq!(card_network("411111111111111"), Some(("Visa", false)));
+        assert_eq!(card_network("7111111111111111"), None);
+    }
+
+    #[test]
+    fn test_parse_pan_masked() {
+        let id_info = parse("4111 1111 1111 1111", false, None).unwrap();
+        assert_eq!(id_info.version.unwrap(), "Visa");
+        assert_eq!(id_info.standard, "4111 11** **** 1111");
+        assert_eq!(id_info.integer, None);
+        assert_eq!(id_info.hex, None);
+        assert_eq!(id_info.sequence, None);
+        let id_info = parse("378282246310005", false, None).unwrap();
+        assert_eq!(id_info.standard, "3782 82**** *0005");
+    }
+
+    #[test]
+    fn test_parse_pan_revealed() {
+        let id_info = parse("4111-1111-1111-1111", true, None).unwrap();
+        assert_eq!(id_info.standard, "4111 1111 1111 1111");
+        assert_eq!(id_info.integer, Some(4111111111111111));
+        assert_eq!(id_info.node3.unwrap(), "4 (Major Industry Identifier: Banking and financial)");
+    }
+
+    #[test]
+    fn test_parse_pan_invalid() {
+        assert!(parse("4111111111111112", false, None).is_none()); // Wrong check digit.
+        assert!(parse("7111111111111114", false, None).is_none()); // Unknown network.
+        let id_info = parse("7111111111111114", false, Some(IdFormat::Pan)).unwrap();
+        assert_eq!(id_info.version.unwrap(), "Unknown network");
+    }
+}
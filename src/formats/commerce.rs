@@ -4,7 +4,7 @@ use crate::schema::{Args, IDInfo};
 use crate::utils::{factor_size_hex_bits_color_from_text, repeat_char};
 
 #[allow(clippy::indexing_slicing)]
-fn gtin_check_digit(digits: &[u8]) -> u8 {
+pub fn gtin_check_digit(digits: &[u8]) -> u8 {
     let len = digits.len();
     let sum: u32 = digits
         .iter()
@@ -326,6 +326,15 @@ mod tests {
             relative: false,
             salt: None,
             epoch: None,
+            time_unit: None,
+            tz: None,
+            near: None,
+            between: None,
+            namespace: None,
+            name: None,
+            names_from: None,
+            reveal: false,
+            now: None,
         }
     }
 
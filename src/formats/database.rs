@@ -0,0 +1,147 @@
+use uuid::Uuid;
+
+use crate::formats::uuid::{has_valid_version, parse_uuid};
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::now;
+
+const YEAR_2000_SECS: u64 = 946684800;
+
+#[derive(Clone, Copy, PartialEq)]
+enum StorageForm {
+    PostgresBytea,
+    OracleHexToRaw,
+    HexLiteral,
+    HexString,
+    BareHex,
+}
+
+impl StorageForm {
+    fn name(&self) -> &str {
+        match self {
+            StorageForm::PostgresBytea => "PostgreSQL bytea",
+            StorageForm::OracleHexToRaw => "Oracle RAW",
+            StorageForm::HexLiteral => "SQL hex literal",
+            StorageForm::HexString => "SQL hex string",
+            StorageForm::BareHex => "Hex",
+        }
+    }
+}
+
+/// Strips `\x…`, `HEXTORAW('…')`, `0x…` or `X'…'` and returns the 32 hex digits inside.
+fn unwrap_storage_form(id: &str) -> Option<(StorageForm, &str)> {
+    let upper = id.to_uppercase();
+    let (form, hex_digits) = if let Some(rest) = id.strip_prefix("\\x") {
+        (StorageForm::PostgresBytea, rest)
+    } else if upper.starts_with("HEXTORAW('") && upper.ends_with("')") {
+        (StorageForm::OracleHexToRaw, id.get(10..id.len() - 2)?)
+    } else if let Some(rest) = id.strip_prefix("0x").or_else(|| id.strip_prefix("0X")) {
+        (StorageForm::HexLiteral, rest)
+    } else if upper.starts_with("X'") && upper.ends_with('\'') {
+        (StorageForm::HexString, id.get(2..id.len() - 1)?)
+    } else {
+        (StorageForm::BareHex, id)
+    };
+    if hex_digits.len() != 32 || !hex_digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some((form, hex_digits))
+}
+
+/// Reverts MySQL's `UUID_TO_BIN(uuid, 1)`, which moves time_hi and time_mid in front of time_low.
+fn unswap_mysql(bytes: &[u8; 16]) -> Uuid {
+    let [h1, h2, m1, m2, l1, l2, l3, l4, rest @ ..] = *bytes;
+    let [r1, r2, r3, r4, r5, r6, r7, r8] = rest;
+    Uuid::from_bytes([l1, l2, l3, l4, m1, m2, h1, h2, r1, r2, r3, r4, r5, r6, r7, r8])
+}
+
+fn is_plausible_v1(args: &Args, uuid: &Uuid) -> bool {
+    let now = u64::try_from(now(args).timestamp()).unwrap_or_default();
+    uuid.get_version_num() == 1 && uuid.get_timestamp().is_some_and(|ts| (YEAR_2000_SECS..=now + 86_400).contains(&ts.to_unix().0))
+}
+
+pub fn parse_database_uuid(args: &Args) -> Option<IDInfo> {
+    let (form, hex_digits) = unwrap_storage_form(args.id.trim())?;
+    let bytes: [u8; 16] = hex::decode(hex_digits).ok()?.try_into().ok()?;
+    let as_is = Uuid::from_bytes(bytes);
+    let mysql = unswap_mysql(&bytes);
+    let microsoft = Uuid::from_bytes_le(bytes);
+
+    let (uuid, convention) = if has_valid_version(&as_is) {
+        (as_is, None)
+    } else if is_plausible_v1(args, &mysql) {
+        (mysql, Some("MySQL UUID_TO_BIN swap flag undone"))
+    } else if form == StorageForm::HexLiteral && has_valid_version(&microsoft) {
+        (microsoft, Some("SQL Server uniqueidentifier byte order"))
+    } else {
+        (as_is, None)
+    };
+    // Bare hex is only interesting when a storage convention had to be undone:
+    if form == StorageForm::BareHex && convention.is_none() && args.force != Some(IdFormat::DatabaseUuid) {
+        return None;
+    }
+
+    let mut new_args: Args = args.clone();
+    new_args.id = uuid.to_string();
+    new_args.force = None;
+    let mut id_info = parse_uuid(&new_args)?;
+    id_info.id_type = format!("{} of {}", form.name(), id_info.id_type);
+    id_info.standard = args.id.trim().to_string();
+    id_info.uuid_wrap = Some(uuid.to_string());
+    id_info.parsed = Some(match convention {
+        Some(convention) => format!("from hex, {}", convention),
+        None => "from hex".to_string(),
+    });
+    Some(id_info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(id: &str) -> Option<IDInfo> {
+        parse_database_uuid(&Args {
+            id: id.to_string(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_parse_postgres_bytea() {
+        let id_info = parse("\\x8584c629371f4fc6a1afae5201e8f210").unwrap();
+        assert_eq!(id_info.id_type, "PostgreSQL bytea of UUID (RFC-4122)");
+        assert_eq!(id_info.uuid_wrap.unwrap(), "8584c629-371f-4fc6-a1af-ae5201e8f210");
+        assert_eq!(id_info.parsed.unwrap(), "from hex");
+    }
+
+    #[test]
+    fn test_parse_oracle_hextoraw() {
+        let id_info = parse("HEXTORAW('8584C629371F4FC6A1AFAE5201E8F210')").unwrap();
+        assert_eq!(id_info.id_type, "Oracle RAW of UUID (RFC-4122)");
+        assert_eq!(id_info.uuid_wrap.unwrap(), "8584c629-371f-4fc6-a1af-ae5201e8f210");
+    }
+
+    #[test]
+    fn test_parse_mysql_swap_flag() {
+        for id in ["11efa51816689a10aa744ec6089be97a", "0x11EFA51816689A10AA744EC6089BE97A", "X'11efa51816689a10aa744ec6089be97a'"] {
+            let id_info = parse(id).unwrap();
+            assert_eq!(id_info.uuid_wrap.unwrap(), "16689a10-a518-11ef-aa74-4ec6089be97a");
+            assert_eq!(id_info.parsed.unwrap(), "from hex, MySQL UUID_TO_BIN swap flag undone");
+            assert_eq!(id_info.datetime.unwrap(), "2024-11-17T19:13:54.552Z");
+        }
+    }
+
+    #[test]
+    fn test_parse_sql_server_binary() {
+        let id_info = parse("0x29C684851F37C64FA1AFAE5201E8F210").unwrap();
+        assert_eq!(id_info.id_type, "SQL hex literal of UUID (RFC-4122)");
+        assert_eq!(id_info.uuid_wrap.unwrap(), "8584c629-371f-4fc6-a1af-ae5201e8f210");
+        assert_eq!(id_info.parsed.unwrap(), "from hex, SQL Server uniqueidentifier byte order");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(parse("8584c629371f4fc6a1afae5201e8f210").is_none());
+        assert!(parse("\\x8584c629").is_none());
+        assert!(parse("HEXTORAW('zz')").is_none());
+    }
+}
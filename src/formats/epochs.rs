@@ -0,0 +1,441 @@
+use chrono::NaiveDateTime;
+use std::fmt::Write;
+
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::{parse_scaled_decimal, repeat_char, unix_nanos_to_iso8601};
+
+const NANOS: i128 = 1_000_000_000;
+const DAY_SECONDS: i128 = 86_400;
+const WEEK_SECONDS: i128 = 604_800;
+
+// Seconds between each epoch and 1970-01-01T00:00:00Z:
+const OFFSET_0001: i128 = 62_135_596_800;
+const OFFSET_1601: i128 = 11_644_473_600;
+const OFFSET_1900: i128 = 2_208_988_800;
+const OFFSET_1904: i128 = 2_082_844_800;
+const OFFSET_2001: i128 = -978_307_200;
+const OFFSET_GPS: i128 = -315_964_800;
+
+const DOTNET_MAX_TICKS: u64 = 3_155_378_975_999_999_999; // 9999-12-31T23:59:59.9999999
+const EXCEL_MAX_SERIAL: i128 = 2_958_466; // 10000-01-01
+const JULIAN_DAY_UNIX_EPOCH: i128 = 2_440_587_500_000_000; // 2440587.5, scaled by 10^9.
+const AD_NEVER: u64 = i64::MAX as u64;
+
+// Unix times when GPS time got one more second ahead of UTC.
+#[rustfmt::skip]
+const GPS_LEAP_SECONDS: [i128; 18] = [
+    362793600, 394329600, 425865600, 489024000, 567993600, 631152000, 662688000, 709948800, 741484800,
+    773020800, 820454400, 867715200, 915148800, 1136073600, 1230768000, 1341100800, 1435708800, 1483228800,
+];
+
+struct EpochTimestamp<'a> {
+    id_type: &'a str,
+    version: String,
+    unix_ns: i128,
+    raw: Option<(u64, usize)>, // Integer value and its size in bits.
+    node1: Option<String>,
+    high_confidence: bool,
+}
+
+fn epoch_info(args: &Args, epoch_timestamp: EpochTimestamp) -> Option<IDInfo> {
+    let (timestamp, datetime) = unix_nanos_to_iso8601(epoch_timestamp.unix_ns);
+    if datetime == "Invalid" {
+        return None;
+    }
+    let mut id_info = IDInfo {
+        id_type: epoch_timestamp.id_type.to_string(),
+        version: Some(epoch_timestamp.version),
+        standard: args.id.trim().to_string(),
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        node1: epoch_timestamp.node1,
+        high_confidence: epoch_timestamp.high_confidence,
+        ..Default::default()
+    };
+    if let Some((value, size)) = epoch_timestamp.raw {
+        let all_bytes = value.to_be_bytes();
+        let bytes = all_bytes.get(8 - size / 8..)?;
+        id_info.integer = Some(value as u128);
+        id_info.parsed = Some("as integer".to_string());
+        id_info.size = size as u16;
+        id_info.hex = Some(hex::encode(bytes));
+        id_info.bits = Some(bytes.iter().fold(String::new(), |mut output, c| {
+            let _ = write!(output, "{c:08b}");
+            output
+        }));
+        id_info.color_map = Some(repeat_char('3', size));
+    }
+    Some(id_info)
+}
+
+pub fn parse_filetime(args: &Args) -> Option<IDInfo> {
+    let value = args.id.trim().parse::<u64>().ok()?;
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "Windows FILETIME",
+            version: "100 ns intervals since 1601-01-01".to_string(),
+            unix_ns: value as i128 * 100 - OFFSET_1601 * NANOS,
+            raw: Some((value, 64)),
+            node1: None,
+            high_confidence: false,
+        },
+    )
+}
+
+pub fn parse_dotnet_ticks(args: &Args) -> Option<IDInfo> {
+    let value = args.id.trim().parse::<u64>().ok()?;
+    if value > DOTNET_MAX_TICKS {
+        return None;
+    }
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: ".NET ticks",
+            version: "100 ns intervals since 0001-01-01".to_string(),
+            unix_ns: value as i128 * 100 - OFFSET_0001 * NANOS,
+            raw: Some((value, 64)),
+            node1: None,
+            high_confidence: false,
+        },
+    )
+}
+
+pub fn parse_cocoa(args: &Args) -> Option<IDInfo> {
+    let value_ns = parse_scaled_decimal(args.id.trim(), 9)?;
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "Cocoa timestamp (Apple)",
+            version: "Seconds since 2001-01-01 (Core Data)".to_string(),
+            unix_ns: value_ns - OFFSET_2001 * NANOS,
+            raw: None,
+            node1: None,
+            high_confidence: false,
+        },
+    )
+}
+
+pub fn parse_webkit(args: &Args) -> Option<IDInfo> {
+    let value = args.id.trim().parse::<u64>().ok()?;
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "WebKit timestamp (Chrome)",
+            version: "Microseconds since 1601-01-01".to_string(),
+            unix_ns: value as i128 * 1_000 - OFFSET_1601 * NANOS,
+            raw: Some((value, 64)),
+            node1: None,
+            high_confidence: false,
+        },
+    )
+}
+
+fn parse_ntp_value(id: &str) -> Option<(u64, bool)> {
+    let hex_digits = |text: &str| !text.is_empty() && text.len() <= 8 && text.chars().all(|c| c.is_ascii_hexdigit());
+    let unprefixed = id.strip_prefix("0x").unwrap_or(id);
+    if let Some((seconds, fraction)) = unprefixed.split_once('.') {
+        if !hex_digits(seconds) || !hex_digits(fraction) {
+            return None;
+        }
+        let seconds = u64::from_str_radix(seconds, 16).ok()?;
+        let fraction = u64::from_str_radix(&format!("{:0<8}", fraction), 16).ok()?;
+        return Some((seconds << 32 | fraction, true));
+    }
+    let value = match id.strip_prefix("0x") {
+        Some(hex_value) => u64::from_str_radix(hex_value, 16).ok()?,
+        None => id.parse::<u64>().ok()?,
+    };
+    Some((value, value > u32::MAX as u64))
+}
+
+pub fn parse_ntp(args: &Args) -> Option<IDInfo> {
+    let (value, fixed_point) = parse_ntp_value(args.id.trim())?;
+    let (seconds, fraction_ns, version) = match fixed_point {
+        true => (value >> 32, ((value & 0xffff_ffff) as i128 * NANOS) >> 32, "64-bit fixed point since 1900-01-01"),
+        false => (value, 0, "Seconds since 1900-01-01"),
+    };
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "NTP timestamp",
+            version: version.to_string(),
+            unix_ns: (seconds as i128 - OFFSET_1900) * NANOS + fraction_ns,
+            raw: Some((value, if fixed_point { 64 } else { 32 })),
+            node1: None,
+            high_confidence: false,
+        },
+    )
+}
+
+fn gps_leap_seconds(gps_as_unix: i128) -> i128 {
+    GPS_LEAP_SECONDS.iter().enumerate().filter(|(i, leap)| gps_as_unix > *leap + *i as i128).count() as i128
+}
+
+pub fn parse_gps(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    let (gps_ns, version, high_confidence) = match id.split_once([':', '/']) {
+        Some((week, seconds_of_week)) => {
+            let week = week.parse::<u32>().ok()?;
+            let seconds_ns = parse_scaled_decimal(seconds_of_week, 9)?;
+            if !(0..WEEK_SECONDS * NANOS).contains(&seconds_ns) {
+                return None;
+            }
+            (week as i128 * WEEK_SECONDS * NANOS + seconds_ns, format!("Week {}, second {} of the week", week, seconds_of_week), true)
+        }
+        None => {
+            let seconds_ns = parse_scaled_decimal(id, 9)?;
+            if seconds_ns < 0 {
+                return None;
+            }
+            (seconds_ns, "Seconds since 1980-01-06".to_string(), false)
+        }
+    };
+    let gps_as_unix_ns = gps_ns - OFFSET_GPS * NANOS;
+    let leap_seconds = gps_leap_seconds(gps_as_unix_ns.div_euclid(NANOS));
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "GPS time",
+            version,
+            unix_ns: gps_as_unix_ns - leap_seconds * NANOS,
+            raw: None,
+            node1: Some(format!("{} (Leap seconds ahead of UTC)", leap_seconds)),
+            high_confidence,
+        },
+    )
+}
+
+pub fn parse_excel(args: &Args) -> Option<IDInfo> {
+    let serial = parse_scaled_decimal(args.id.trim(), 9)?;
+    if serial <= 0 || serial >= EXCEL_MAX_SERIAL * NANOS {
+        return None;
+    }
+    // Excel counts 1900-02-29 as a real day (serial 60), so the dates before it are one day off.
+    let epoch_serial = match serial {
+        s if s < 60 * NANOS => 25_568,
+        s if s < 61 * NANOS => return None,
+        _ => 25_569,
+    };
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "Excel serial date",
+            version: "Days since 1899-12-30 (1900 date system)".to_string(),
+            unix_ns: (serial - epoch_serial * NANOS) * DAY_SECONDS,
+            raw: None,
+            node1: None,
+            high_confidence: false,
+        },
+    )
+}
+
+pub fn parse_julian_day(args: &Args) -> Option<IDInfo> {
+    let julian_day = parse_scaled_decimal(args.id.trim(), 9)?;
+    if julian_day < 0 {
+        return None;
+    }
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "Julian Day",
+            version: "Days since 4713-11-24 BC, noon".to_string(),
+            unix_ns: (julian_day - JULIAN_DAY_UNIX_EPOCH) * DAY_SECONDS,
+            raw: None,
+            node1: None,
+            high_confidence: false,
+        },
+    )
+}
+
+fn parse_generalized_time(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    let (local_time, offset_seconds) = match id.strip_suffix('Z') {
+        Some(value) => (value, 0),
+        None => {
+            let (value, offset) = id.split_at_checked(id.len().checked_sub(5)?)?;
+            let sign = match offset.chars().next()? {
+                '+' => 1,
+                '-' => -1,
+                _ => return None,
+            };
+            let hours = offset.get(1..3)?.parse::<i128>().ok()?;
+            let minutes = offset.get(3..5)?.parse::<i128>().ok()?;
+            (value, sign * (hours * 3600 + minutes * 60))
+        }
+    };
+    let (whole, fraction) = local_time.split_once(['.', ',']).unwrap_or((local_time, ""));
+    if whole.len() != 14 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let naive = NaiveDateTime::parse_from_str(whole, "%Y%m%d%H%M%S").ok()?;
+    let fraction_ns = parse_scaled_decimal(&format!("0.{}", fraction), 9)?;
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "LDAP timestamp",
+            version: "Generalized Time".to_string(),
+            unix_ns: naive.and_utc().timestamp() as i128 * NANOS + fraction_ns - offset_seconds * NANOS,
+            raw: None,
+            node1: None,
+            high_confidence: true,
+        },
+    )
+}
+
+pub fn parse_ldap(args: &Args) -> Option<IDInfo> {
+    if let Some(id_info) = parse_generalized_time(args) {
+        return Some(id_info);
+    }
+    // Active Directory integers are indistinguishable from FILETIME, so only parse them when forced:
+    if args.force != Some(IdFormat::Ldap) {
+        return None;
+    }
+    let value = args.id.trim().parse::<u64>().ok()?;
+    if value == 0 || value == AD_NEVER {
+        return Some(IDInfo {
+            id_type: "LDAP timestamp".to_string(),
+            version: Some("Active Directory, never (no date set)".to_string()),
+            standard: args.id.trim().to_string(),
+            integer: Some(value as u128),
+            high_confidence: true,
+            ..Default::default()
+        });
+    }
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "LDAP timestamp",
+            version: "Active Directory, 100 ns intervals since 1601-01-01".to_string(),
+            unix_ns: value as i128 * 100 - OFFSET_1601 * NANOS,
+            raw: Some((value, 64)),
+            node1: None,
+            high_confidence: true,
+        },
+    )
+}
+
+pub fn parse_hfs(args: &Args) -> Option<IDInfo> {
+    let value = args.id.trim().parse::<u32>().ok()?;
+    epoch_info(
+        args,
+        EpochTimestamp {
+            id_type: "HFS+ timestamp",
+            version: "Seconds since 1904-01-01".to_string(),
+            unix_ns: (value as i128 - OFFSET_1904) * NANOS,
+            raw: Some((value as u64, 32)),
+            node1: None,
+            high_confidence: false,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn datetime(parser: fn(&Args) -> Option<IDInfo>, id: &str) -> String {
+        parser(&Args {
+            id: id.to_string(),
+            ..Default::default()
+        })
+        .unwrap()
+        .datetime
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_filetime() {
+        assert_eq!(datetime(parse_filetime, "133485408000000000"), "2024-01-01T00:00:00.000000000Z");
+        assert_eq!(datetime(parse_filetime, "0"), "1601-01-01T00:00:00.000000000Z");
+    }
+
+    #[test]
+    fn test_parse_dotnet_ticks() {
+        assert_eq!(datetime(parse_dotnet_ticks, "638396640000000000"), "2024-01-01T00:00:00.000000000Z");
+        assert!(
+            parse_dotnet_ticks(&Args {
+                id: "3155378976000000000".to_string(),
+                ..Default::default()
+            })
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_cocoa() {
+        assert_eq!(datetime(parse_cocoa, "725760000"), "2024-01-01T00:00:00.000000000Z");
+        assert_eq!(datetime(parse_cocoa, "-978307200.5"), "1969-12-31T23:59:59.500000000Z");
+    }
+
+    #[test]
+    fn test_parse_webkit() {
+        assert_eq!(datetime(parse_webkit, "13348540800000000"), "2024-01-01T00:00:00.000000000Z");
+    }
+
+    #[test]
+    fn test_parse_ntp() {
+        assert_eq!(datetime(parse_ntp, "3913056000"), "2024-01-01T00:00:00.000000000Z");
+        assert_eq!(datetime(parse_ntp, "0xe93c7f00.80000000"), "2024-01-01T00:00:00.500000000Z");
+        assert_eq!(datetime(parse_ntp, "16806447549564059648"), "2024-01-01T00:00:00.500000000Z");
+    }
+
+    #[test]
+    fn test_parse_gps() {
+        assert_eq!(datetime(parse_gps, "2295:86418"), "2024-01-01T00:00:00.000000000Z");
+        assert_eq!(datetime(parse_gps, "1388102418"), "2024-01-01T00:00:00.000000000Z");
+        assert_eq!(datetime(parse_gps, "0:0"), "1980-01-06T00:00:00.000000000Z");
+        assert!(
+            parse_gps(&Args {
+                id: "2295:604800".to_string(),
+                ..Default::default()
+            })
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_excel() {
+        assert_eq!(datetime(parse_excel, "45292"), "2024-01-01T00:00:00.000000000Z");
+        assert_eq!(datetime(parse_excel, "45292.5"), "2024-01-01T12:00:00.000000000Z");
+        assert_eq!(datetime(parse_excel, "1"), "1900-01-01T00:00:00.000000000Z");
+        assert!(
+            parse_excel(&Args {
+                id: "60".to_string(),
+                ..Default::default()
+            })
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_julian_day() {
+        assert_eq!(datetime(parse_julian_day, "2460310.5"), "2024-01-01T00:00:00.000000000Z");
+        assert_eq!(datetime(parse_julian_day, "2440587.5"), "1970-01-01T00:00:00.000000000Z");
+    }
+
+    #[test]
+    fn test_parse_ldap() {
+        assert_eq!(datetime(parse_ldap, "20240101000000Z"), "2024-01-01T00:00:00.000000000Z");
+        assert_eq!(datetime(parse_ldap, "20240101020000.5+0200"), "2024-01-01T00:00:00.500000000Z");
+        assert!(
+            parse_ldap(&Args {
+                id: "133503840000000000".to_string(),
+                ..Default::default()
+            })
+            .is_none()
+        );
+        let forced = parse_ldap(&Args {
+            id: "9223372036854775807".to_string(),
+            force: Some(IdFormat::Ldap),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(forced.version.unwrap(), "Active Directory, never (no date set)");
+    }
+
+    #[test]
+    fn test_parse_hfs() {
+        assert_eq!(datetime(parse_hfs, "3786912000"), "2024-01-01T00:00:00.000000000Z");
+    }
+}
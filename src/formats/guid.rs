@@ -0,0 +1,117 @@
+use uuid::{Uuid, Variant};
+
+use crate::formats::uuid::{COLOR_MAP_UUID_GENERIC, has_valid_version, parse_uuid};
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::{now, repeat_char, unix_nanos_to_iso8601};
+
+const SQL_SERVER_2005_MS: u128 = 1130803200000; // 2005-11-01, release of NEWSEQUENTIALID().
+const UNIX_TO_1900_DAYS: i128 = 25567;
+
+/// Reads the UUID as .NET's `Guid.ToByteArray()` would write it: the first three groups are little-endian.
+fn swap_byte_order(args: &Args) -> Option<(Uuid, Uuid)> {
+    let uuid = Uuid::try_parse(args.id.trim()).ok()?;
+    Some((uuid, Uuid::from_bytes_le(uuid.into_bytes())))
+}
+
+fn parse_swapped(args: &Args, swapped: &Uuid) -> Option<IDInfo> {
+    let mut new_args: Args = args.clone();
+    new_args.id = swapped.to_string();
+    new_args.force = None;
+    let mut id_info = parse_uuid(&new_args)?;
+    id_info.standard = args.id.trim().to_string();
+    id_info.uuid_wrap = Some(swapped.to_string());
+    id_info.parsed = Some("from hex, Microsoft byte order".to_string());
+    Some(id_info)
+}
+
+pub fn parse_guid_bytes(args: &Args) -> Option<IDInfo> {
+    let (uuid, swapped) = swap_byte_order(args)?;
+    if args.force != Some(IdFormat::GuidBytes) && (has_valid_version(&uuid) || !has_valid_version(&swapped)) {
+        return None;
+    }
+    let mut id_info = parse_swapped(args, &swapped)?;
+    id_info.id_type = format!("Microsoft byte order of {}", id_info.id_type);
+    Some(id_info)
+}
+
+/// SQL Server's `NEWSEQUENTIALID()` is a UUIDv1 from `UuidCreateSequential()` with its first three groups byte-swapped.
+pub fn parse_newsequentialid(args: &Args) -> Option<IDInfo> {
+    let (uuid, swapped) = swap_byte_order(args)?;
+    if swapped.get_variant() != Variant::RFC4122 || swapped.get_version_num() != 1 {
+        return None;
+    }
+    if args.force != Some(IdFormat::Newsequentialid) {
+        let now_ms = u128::try_from(now(args).timestamp_millis()).unwrap_or_default();
+        let created_ms = swapped.get_timestamp().map(|ts| u128::from(ts.to_unix().0) * 1000)?;
+        if has_valid_version(&uuid) || !(SQL_SERVER_2005_MS..=now_ms + 86_400_000).contains(&created_ms) {
+            return None;
+        }
+    }
+    let mut id_info = parse_swapped(args, &swapped)?;
+    id_info.id_type = "SQL Server NEWSEQUENTIALID".to_string();
+    Some(id_info)
+}
+
+/// COMB GUIDs (Jimmy Nilsson, NHibernate) replace the last six bytes of a random GUID with
+/// days since 1900 (2 bytes) and the time of day in 1/300 seconds (4 bytes), like SQL Server's `datetime`.
+pub fn parse_comb(args: &Args) -> Option<IDInfo> {
+    let uuid = Uuid::try_parse(args.id.trim()).ok()?;
+    let node = uuid.as_u128() & 0xffff_ffff_ffff;
+    let days = (node >> 32) as i128;
+    let ticks = (node & 0xffff_ffff) as i128;
+    if ticks >= 300 * 86_400 {
+        return None;
+    }
+    let unix_ns = (days - UNIX_TO_1900_DAYS) * 86_400_000_000_000 + ticks * 10_000_000 / 3;
+    let (timestamp, datetime) = unix_nanos_to_iso8601(unix_ns);
+
+    let mut new_args: Args = args.clone();
+    new_args.force = None;
+    let mut id_info = parse_uuid(&new_args)?;
+    id_info.id_type = format!("COMB of {}", id_info.id_type);
+    id_info.entropy = id_info.entropy.saturating_sub(48);
+    id_info.timestamp = Some(timestamp);
+    id_info.datetime = Some(datetime);
+    id_info.color_map = Some(COLOR_MAP_UUID_GENERIC.chars().take(80).collect::<String>() + &repeat_char('3', 48));
+    id_info.high_confidence = false;
+    Some(id_info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(id: &str) -> Args {
+        Args {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_guid_bytes() {
+        let id_info = parse_guid_bytes(&args("29c684851f37c64fa1afae5201e8f210")).unwrap();
+        assert_eq!(id_info.id_type, "Microsoft byte order of UUID (RFC-4122)");
+        assert_eq!(id_info.version.unwrap(), "4 (random)");
+        assert_eq!(id_info.uuid_wrap.unwrap(), "8584c629-371f-4fc6-a1af-ae5201e8f210");
+        assert!(parse_guid_bytes(&args("8584c629-371f-4fc6-a1af-ae5201e8f210")).is_none());
+    }
+
+    #[test]
+    fn test_parse_newsequentialid() {
+        let id_info = parse_newsequentialid(&args("109a6816-18a5-ef11-aa74-4ec6089be97a")).unwrap();
+        assert_eq!(id_info.id_type, "SQL Server NEWSEQUENTIALID");
+        assert_eq!(id_info.uuid_wrap.unwrap(), "16689a10-a518-11ef-aa74-4ec6089be97a");
+        assert_eq!(id_info.datetime.unwrap(), "2024-11-17T19:13:54.552Z");
+        assert!(parse_newsequentialid(&args("16689a10-a518-11ef-aa74-4ec6089be97a")).is_none());
+    }
+
+    #[test]
+    fn test_parse_comb() {
+        let id_info = parse_comb(&args("8584c629-371f-4fc6-a1af-b22b00cf5a2e")).unwrap();
+        assert_eq!(id_info.id_type, "COMB of UUID (RFC-4122)");
+        assert_eq!(id_info.datetime.unwrap(), "2024-11-17T12:34:56.793333333Z");
+        assert_eq!(id_info.entropy, 74);
+        assert!(parse_comb(&args("8584c629-371f-4fc6-a1af-ffffffffffff")).is_none());
+    }
+}
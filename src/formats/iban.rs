@@ -26,6 +26,135 @@ fn iban_length(country: &str) -> Option<u8> {
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum BbanField {
+    Bank,
+    Branch,
+    AccountType,
+    Reserved,
+    Account,
+    NationalCheck,
+}
+
+/// BBAN structure from the SWIFT IBAN registry, for countries where it is split in well-known fields.
+#[rustfmt::skip]
+fn bban_layout(country: &str) -> Option<&'static [(BbanField, usize)]> {
+    use BbanField::*;
+    let layout: &[(BbanField, usize)] = match country {
+        "AD" => &[(Bank, 4), (Branch, 4), (Account, 12)],
+        "AT" | "LT" => &[(Bank, 5), (Account, 11)],
+        "BE" => &[(Bank, 3), (Account, 7), (NationalCheck, 2)],
+        "BG" => &[(Bank, 4), (Branch, 4), (AccountType, 2), (Account, 8)],
+        "CH" | "LI" => &[(Bank, 5), (Account, 12)],
+        "CY" => &[(Bank, 3), (Branch, 5), (Account, 16)],
+        "CZ" | "RO" | "SK" => &[(Bank, 4), (Account, 16)],
+        "DE" => &[(Bank, 8), (Account, 10)],
+        "DK" | "NL" => &[(Bank, 4), (Account, 10)],
+        "EE" => &[(Bank, 2), (Account, 14)],
+        "ES" => &[(Bank, 4), (Branch, 4), (NationalCheck, 2), (Account, 10)],
+        "FI" => &[(Bank, 3), (Account, 11)],
+        "FR" | "MC" => &[(Bank, 5), (Branch, 5), (Account, 11), (NationalCheck, 2)],
+        "GB" | "IE" => &[(Bank, 4), (Branch, 6), (Account, 8)],
+        "GR" => &[(Bank, 3), (Branch, 4), (Account, 16)],
+        "HR" => &[(Bank, 7), (Account, 10)],
+        "HU" => &[(Bank, 3), (Branch, 4), (Account, 17)],
+        "IS" => &[(Bank, 4), (Account, 18)],
+        "IT" | "SM" => &[(NationalCheck, 1), (Bank, 5), (Branch, 5), (Account, 12)],
+        "LU" => &[(Bank, 3), (Account, 13)],
+        "LV" => &[(Bank, 4), (Account, 13)],
+        "MT" => &[(Bank, 4), (Branch, 5), (Account, 18)],
+        "NO" => &[(Bank, 4), (Account, 6), (NationalCheck, 1)],
+        "PL" => &[(Bank, 8), (Account, 16)],
+        "PT" => &[(Bank, 4), (Branch, 4), (Account, 11), (NationalCheck, 2)],
+        "SE" => &[(Bank, 3), (Account, 17)],
+        "SI" => &[(Bank, 5), (Account, 10)],
+        "TR" => &[(Bank, 5), (Reserved, 1), (Account, 16)],
+        _ => return None,
+    };
+    Some(layout)
+}
+
+/// Splits the BBAN in its fields, following the country's layout.
+fn split_bban<'a>(layout: &[(BbanField, usize)], bban: &'a str) -> Option<Vec<(BbanField, &'a str)>> {
+    let mut offset = 0;
+    let mut fields = vec![];
+    for (field, len) in layout {
+        fields.push((*field, bban.get(offset..offset + len)?));
+        offset += len;
+    }
+    Some(fields)
+}
+
+/// Verifies the national check digits of the countries that define them; `None` elsewhere.
+fn national_check_valid(country: &str, bban: &str) -> Option<bool> {
+    match country {
+        "BE" => {
+            let remainder = mod97(bban.get(0..10)?);
+            Some(bban.get(10..12)?.parse::<u32>().ok()? == if remainder == 0 { 97 } else { remainder })
+        }
+        "ES" => {
+            let digit = |digits: &str| -> Option<u32> {
+                let mut sum = 0;
+                for (c, weight) in digits.chars().zip([1, 2, 4, 8, 5, 10, 9, 7, 3, 6]) {
+                    sum += c.to_digit(10)? * weight;
+                }
+                Some(match 11 - sum % 11 {
+                    11 => 0,
+                    10 => 1,
+                    value => value,
+                })
+            };
+            let expected = format!("{}{}", digit(&format!("00{}", bban.get(0..8)?))?, digit(bban.get(10..20)?)?);
+            Some(bban.get(8..10)? == expected)
+        }
+        "FR" | "MC" => {
+            // Letters of the RIB account number count as digits: A-I 1-9, J-R 1-9, S-Z 2-9.
+            const LETTER_DIGITS: &[u8] = b"12345678912345678923456789";
+            let numeric = |text: &str| -> Option<u128> {
+                text.chars()
+                    .map(|c| match c {
+                        '0'..='9' => Some(c),
+                        'A'..='Z' => LETTER_DIGITS.get(c as usize - 'A' as usize).map(|digit| *digit as char),
+                        _ => None,
+                    })
+                    .collect::<Option<String>>()?
+                    .parse::<u128>()
+                    .ok()
+            };
+            let sum = 89 * numeric(bban.get(0..5)?)? + 15 * numeric(bban.get(5..10)?)? + 3 * numeric(bban.get(10..21)?)?;
+            Some(bban.get(21..23)?.parse::<u128>().ok()? == 97 - sum % 97)
+        }
+        "IT" | "SM" => {
+            // CIN: characters in odd positions are mapped through this table, even ones count as 0-25.
+            const ODD_VALUES: [u32; 26] = [1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24, 23];
+            let mut sum = 0;
+            for (index, c) in bban.get(1..23)?.chars().enumerate() {
+                let value = match c {
+                    '0'..='9' => c.to_digit(10)?,
+                    'A'..='Z' => c as u32 - 'A' as u32,
+                    _ => return None,
+                };
+                sum += if index % 2 == 0 { *ODD_VALUES.get(value as usize)? } else { value };
+            }
+            Some(bban.get(0..1)? == char::from(b'A' + (sum % 26) as u8).to_string())
+        }
+        "NO" => {
+            let mut sum = 0;
+            for (c, weight) in bban.get(0..10)?.chars().zip([5, 4, 3, 2, 7, 6, 5, 4, 3, 2]) {
+                sum += c.to_digit(10)? * weight;
+            }
+            let expected = match 11 - sum % 11 {
+                11 => 0,
+                10 => return Some(false), // Account numbers with this remainder are never issued.
+                value => value,
+            };
+            Some(bban.get(10..11)?.parse::<u32>().ok()? == expected)
+        }
+        "PT" => Some(bban.get(19..21)?.parse::<u32>().ok()? == 98 - mod97(&format!("{}00", bban.get(0..19)?))),
+        _ => None,
+    }
+}
+
 fn validate_checksum(iban: &str) -> bool {
     let rearranged = format!("{}{}", &iban[4..], &iban[..4]);
     let numeric: String = rearranged
@@ -35,7 +164,7 @@ fn validate_checksum(iban: &str) -> bool {
     mod97(&numeric) == 1
 }
 
-fn mod97(digits: &str) -> u32 {
+pub fn mod97(digits: &str) -> u32 {
     let mut remainder: u32 = 0;
     for c in digits.chars() {
         let digit = c.to_digit(10).unwrap_or(0);
@@ -188,6 +317,55 @@ pub fn parse_iban(args: &Args) -> Option<IDInfo> {
     let country_bits = 16;
     let check_bits = 16;
     let bban_bits = (id_len - 4) * 8;
+    let national_check = national_check_valid(country_code, bban);
+    let mut node2 = Some(format!("{} (BBAN)", bban));
+    let mut node3 = None;
+    let mut bban_color_map = repeat_char('5', bban_bits);
+    if let Some(fields) = bban_layout(country_code).and_then(|layout| split_bban(layout, bban)) {
+        let describe = |field: BbanField, value: &str| match field {
+            BbanField::Bank => format!("{} (Bank code)", value),
+            BbanField::Branch if matches!(country_code, "GB" | "IE") => format!("{} (Sort code)", value),
+            BbanField::Branch => format!("{} (Branch code)", value),
+            BbanField::AccountType => format!("{} (Account type)", value),
+            BbanField::Reserved => format!("{} (Reserved)", value),
+            BbanField::Account => format!("{} (Account number)", value),
+            BbanField::NationalCheck => match national_check {
+                Some(true) => format!("{} (National check digits, valid)", value),
+                Some(false) => format!("{} (National check digits, invalid)", value),
+                None => format!("{} (National check digits)", value),
+            },
+        };
+        let is_bank = |field: &BbanField| matches!(field, BbanField::Bank | BbanField::Branch);
+        node2 = Some(
+            fields
+                .iter()
+                .filter(|(field, _)| is_bank(field))
+                .map(|(field, value)| describe(*field, value))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        node3 = Some(
+            fields
+                .iter()
+                .filter(|(field, _)| !is_bank(field))
+                .map(|(field, value)| describe(*field, value))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+        bban_color_map = fields
+            .iter()
+            .map(|(field, value)| {
+                let color = match field {
+                    BbanField::Bank => '5',
+                    BbanField::Branch => '7',
+                    BbanField::Account => '6',
+                    BbanField::NationalCheck => '3',
+                    BbanField::AccountType | BbanField::Reserved => '0',
+                };
+                repeat_char(color, value.len() * 8)
+            })
+            .collect();
+    }
 
     Some(IDInfo {
         id_type: "IBAN".to_string(),
@@ -201,11 +379,12 @@ pub fn parse_iban(args: &Args) -> Option<IDInfo> {
         } else {
             format!("{} (Invalid Checksum)", check_digits).into()
         },
-        node2: Some(format!("{} (BBAN)", bban)),
+        node2,
+        node3,
         hex,
         bits,
-        color_map: Some(repeat_char('1', country_bits) + &repeat_char('4', check_bits) + &repeat_char('5', bban_bits)),
-        high_confidence: valida_checksum,
+        color_map: Some(repeat_char('1', country_bits) + &repeat_char('4', check_bits) + &bban_color_map),
+        high_confidence: valida_checksum && national_check != Some(false),
         ..Default::default()
     })
 }
@@ -249,7 +428,8 @@ mod tests {
         let result = parse_iban(&args("GB29NWBK60161331926819")).unwrap();
         assert_eq!(result.id_type, "IBAN");
         assert_eq!(result.version.unwrap(), "GB (United Kingdom)");
-        assert_eq!(result.node2.unwrap(), "NWBK60161331926819 (BBAN)");
+        assert_eq!(result.node2.unwrap(), "NWBK (Bank code), 601613 (Sort code)");
+        assert_eq!(result.node3.unwrap(), "31926819 (Account number)");
         assert!(result.high_confidence);
     }
 
@@ -277,4 +457,49 @@ mod tests {
         assert_eq!(result.version.unwrap(), "GB (United Kingdom)");
         assert!(result.high_confidence);
     }
+
+    #[test]
+    fn test_bban_layouts_match_lengths() {
+        for country in [
+            "AD", "AT", "BE", "BG", "CH", "CY", "CZ", "DE", "DK", "EE", "ES", "FI", "FR", "GB", "GR", "HR", "HU", "IE", "IS", "IT", "LI", "LT", "LU", "LV", "MC", "MT", "NL", "NO", "PL", "PT", "RO",
+            "SE", "SI", "SK", "SM", "TR",
+        ] {
+            let layout_len: usize = bban_layout(country).unwrap().iter().map(|(_, len)| len).sum();
+            assert_eq!(layout_len + 4, iban_length(country).unwrap() as usize, "{country}");
+        }
+    }
+
+    #[test]
+    fn test_national_check_digits() {
+        for iban in [
+            "BE68539007547034",
+            "ES9121000418450200051332",
+            "FR1420041010050500013M02606",
+            "IT60X0542811101000000123456",
+            "MC5811222000010123456789030",
+            "NO9386011117947",
+            "PT50000201231234567890154",
+            "SM86U0322509800000000270100",
+        ] {
+            let result = parse_iban(&args(iban)).unwrap();
+            assert!(result.node3.unwrap().contains("National check digits, valid"), "{iban}");
+            assert!(result.high_confidence);
+        }
+    }
+
+    #[test]
+    fn test_national_check_digits_invalid() {
+        // Valid IBAN checksum, but the RIB key is wrong:
+        let result = parse_iban(&args("FR8420041010050500013M02607")).unwrap();
+        assert!(result.node1.unwrap().contains("Valid Checksum"));
+        assert!(result.node3.unwrap().contains("National check digits, invalid"));
+        assert!(!result.high_confidence);
+    }
+
+    #[test]
+    fn test_italy_fields() {
+        let result = parse_iban(&args("IT60X0542811101000000123456")).unwrap();
+        assert_eq!(result.node2.unwrap(), "05428 (Bank code), 11101 (Branch code)");
+        assert_eq!(result.node3.unwrap(), "X (National check digits, valid), 000000123456 (Account number)");
+    }
 }
@@ -5,27 +5,42 @@ use crate::id_format::pick_first_valid;
 use crate::schema::{Args, IDInfo};
 use crate::utils::{factor_size_hex_bits_color_from_text, repeat_char};
 
+/// Registration group with its name, from the ISBN ranges bundled with the `isbn` crate.
+fn describe_group(group: &str, name: Result<&str, isbn::IsbnError>) -> String {
+    match name {
+        Ok(name) => format!("{} (Registration group: {})", group, name),
+        Err(_) => format!("{} (Registration group)", group),
+    }
+}
+
 pub fn parse_isbn13(args: &Args) -> Option<IDInfo> {
     let id = Isbn13::from_str(&args.id).ok()?;
     let binding = id.hyphenate().ok()?;
     let parts = binding.split('-').collect::<Vec<_>>();
-    let node1_size = parts.first()?.chars().count() + parts.get(2)?.chars().count();
-    let node2_size = parts.get(2)?.chars().count();
-    let sequence_size = parts.get(3)?.chars().count();
+    let [prefix, group, registrant, publication, _] = parts.as_slice() else {
+        return None;
+    };
+    let isbn10 = match Isbn10::try_from(id) {
+        Ok(isbn10) => format!("{} (ISBN-10)", isbn10.hyphenate().ok()?),
+        Err(_) => format!("No ISBN-10 ({} prefix)", prefix),
+    };
     let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&id.to_string());
 
     Some(IDInfo {
         id_type: "ISBN-13".to_string(),
-        standard: id.hyphenate().unwrap().to_string(),
+        standard: binding.to_string(),
         integer: id.to_string().parse::<u128>().ok(),
         parsed: Some("as ASCII, no dashes".to_string()),
         size,
-        node1: Some(id.registration_group().unwrap_or("-").to_string()),
-        node2: Some(format!("{} (Publisher ID)", parts.get(2)?)),
-        sequence: parts.get(3)?.parse::<u128>().ok(),
+        node1: Some(describe_group(&format!("{}-{}", prefix, group), id.registration_group())),
+        node2: Some(format!("{} (Registrant, publisher prefix)", registrant)),
+        node3: Some(isbn10),
+        sequence: publication.parse::<u128>().ok(),
         hex,
         bits,
-        color_map: Some(repeat_char('4', node1_size * 8) + &repeat_char('5', node2_size * 8) + &repeat_char('6', sequence_size * 8) + &repeat_char('0', 8)),
+        color_map: Some(
+            repeat_char('1', prefix.len() * 8) + &repeat_char('4', group.len() * 8) + &repeat_char('5', registrant.len() * 8) + &repeat_char('6', publication.len() * 8) + &repeat_char('0', 8),
+        ),
         high_confidence: true,
         ..Default::default()
     })
@@ -35,23 +50,25 @@ pub fn parse_isbn10(args: &Args) -> Option<IDInfo> {
     let id = Isbn10::from_str(&args.id).ok()?;
     let binding = id.hyphenate().ok()?;
     let parts = binding.split('-').collect::<Vec<_>>();
-    let node1_size = parts.first()?.chars().count();
-    let node2_size = parts.get(1)?.chars().count();
-    let sequence_size = parts.get(2)?.chars().count();
+    let [group, registrant, publication, _] = parts.as_slice() else {
+        return None;
+    };
+    let isbn13 = Isbn13::from(id);
     let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&id.to_string());
 
     Some(IDInfo {
         id_type: "ISBN-10".to_string(),
-        standard: id.hyphenate().unwrap().to_string(),
+        standard: binding.to_string(),
         integer: id.to_string().parse::<u128>().ok(),
         parsed: Some("as ASCII, no dashes".to_string()),
         size,
-        node1: Some(id.registration_group().unwrap_or("-").to_string()),
-        node2: Some(format!("{} (Publisher ID)", parts.get(1)?)),
-        sequence: parts.get(2)?.parse::<u128>().ok(),
+        node1: Some(describe_group(group, id.registration_group())),
+        node2: Some(format!("{} (Registrant, publisher prefix)", registrant)),
+        node3: Some(format!("{} (ISBN-13)", isbn13.hyphenate().ok()?)),
+        sequence: publication.parse::<u128>().ok(),
         hex,
         bits,
-        color_map: Some(repeat_char('4', node1_size * 8) + &repeat_char('5', node2_size * 8) + &repeat_char('6', sequence_size * 8) + &repeat_char('0', 8)),
+        color_map: Some(repeat_char('4', group.len() * 8) + &repeat_char('5', registrant.len() * 8) + &repeat_char('6', publication.len() * 8) + &repeat_char('0', 8)),
         high_confidence: true,
         ..Default::default()
     })
@@ -60,3 +77,41 @@ pub fn parse_isbn10(args: &Args) -> Option<IDInfo> {
 pub fn parse_isbn(args: &Args) -> Option<IDInfo> {
     pick_first_valid(args, &[parse_isbn13, parse_isbn10])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(id: &str) -> Args {
+        Args {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_isbn13() {
+        let id_info = parse_isbn13(&args("9780306406157")).unwrap();
+        assert_eq!(id_info.standard, "978-0-306-40615-7");
+        assert_eq!(id_info.node1.unwrap(), "978-0 (Registration group: English language)");
+        assert_eq!(id_info.node2.unwrap(), "306 (Registrant, publisher prefix)");
+        assert_eq!(id_info.node3.unwrap(), "0-306-40615-2 (ISBN-10)");
+        assert_eq!(id_info.sequence, Some(40615));
+        assert_eq!(id_info.color_map.unwrap().len(), 104);
+    }
+
+    #[test]
+    fn test_parse_isbn13_979() {
+        let id_info = parse_isbn13(&args("979-10-90636-07-1")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "979-10 (Registration group: France)");
+        assert_eq!(id_info.node3.unwrap(), "No ISBN-10 (979 prefix)");
+    }
+
+    #[test]
+    fn test_parse_isbn10() {
+        let id_info = parse_isbn10(&args("8966261264")).unwrap();
+        assert_eq!(id_info.standard, "89-6626-126-4");
+        assert_eq!(id_info.node1.unwrap(), "89 (Registration group: Korea, Republic)");
+        assert_eq!(id_info.node3.unwrap(), "978-89-6626-126-0 (ISBN-13)");
+    }
+}
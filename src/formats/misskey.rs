@@ -0,0 +1,246 @@
+use basen::BASE36;
+use std::fmt::Write;
+
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::{epoch_ms, factor_size_hex_bits_color_from_text, milliseconds_to_seconds_and_iso8601, now, repeat_char};
+
+const TIME2000: u64 = 946684800000;
+const MEID_TIME_OFFSET: u64 = 0x800000000000;
+const MISSKEY_RELEASE: u64 = 1388534400000; // 2014-01-01, before the first Misskey instances.
+
+fn is_base36_lowercase(text: &str) -> bool {
+    text.chars().all(|c| c.is_ascii_digit() || c.is_ascii_lowercase())
+}
+
+fn is_hex_lowercase(text: &str) -> bool {
+    text.chars().all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+}
+
+/// Unless the format is forced, only accept IDs created between Misskey's release and now.
+fn plausible(args: &Args, id_format: IdFormat, unix_ms: u64) -> bool {
+    let now_ms = u64::try_from(now(args).timestamp_millis()).unwrap_or_default();
+    args.force == Some(id_format) || (MISSKEY_RELEASE..=now_ms + 86_400_000).contains(&unix_ms)
+}
+
+/// Tried after the other 10-character formats, so it is mostly found with `-f misskey-aid`.
+pub fn parse_aid(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if id.len() != 10 || !is_base36_lowercase(id) {
+        return None;
+    }
+    let timestamp_raw: u64 = BASE36.decode_var_len(id.get(0..8)?)?;
+    let counter: u64 = BASE36.decode_var_len(id.get(8..10)?)?;
+    if !plausible(args, IdFormat::MisskeyAid, timestamp_raw + TIME2000) {
+        return None;
+    }
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw, epoch_ms(args, TIME2000));
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+
+    Some(IDInfo {
+        id_type: "Misskey ID".to_string(),
+        version: Some("aid".to_string()),
+        standard: id.to_string(),
+        parsed: Some("as ASCII, with base36 parts".to_string()),
+        size,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        sequence: Some(counter as u128),
+        hex,
+        bits,
+        color_map: Some(repeat_char('3', 64) + &repeat_char('6', 16)),
+        // About one in eight random lowercase base36 strings has a date in range:
+        high_confidence: false,
+        ..Default::default()
+    })
+}
+
+pub fn parse_aidx(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if id.len() != 16 || !is_base36_lowercase(id) {
+        return None;
+    }
+    let timestamp_raw: u64 = BASE36.decode_var_len(id.get(0..8)?)?;
+    let node_id: u64 = BASE36.decode_var_len(id.get(8..12)?)?;
+    let counter: u64 = BASE36.decode_var_len(id.get(12..16)?)?;
+    if !plausible(args, IdFormat::MisskeyAidx, timestamp_raw + TIME2000) {
+        return None;
+    }
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw, epoch_ms(args, TIME2000));
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+
+    Some(IDInfo {
+        id_type: "Misskey ID".to_string(),
+        version: Some("aidx".to_string()),
+        standard: id.to_string(),
+        parsed: Some("as ASCII, with base36 parts".to_string()),
+        size,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        sequence: Some(counter as u128),
+        node1: Some(format!("{} (Node ID)", node_id)),
+        hex,
+        bits,
+        color_map: Some(repeat_char('3', 64) + &repeat_char('4', 32) + &repeat_char('6', 32)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+fn hex_info(id_bytes: &[u8]) -> (Option<String>, Option<String>) {
+    (
+        Some(hex::encode(id_bytes)),
+        Some(id_bytes.iter().fold(String::new(), |mut output, c| {
+            let _ = write!(output, "{c:08b}");
+            output
+        })),
+    )
+}
+
+pub fn parse_meid(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if id.len() != 24 || !is_hex_lowercase(id) {
+        return None;
+    }
+    let timestamp_raw = u64::from_str_radix(id.get(0..12)?, 16).ok()?.checked_sub(MEID_TIME_OFFSET)?;
+    if !plausible(args, IdFormat::MisskeyMeid, timestamp_raw) {
+        return None;
+    }
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw, epoch_ms(args, 0));
+    let id_bytes = hex::decode(id).ok()?;
+    let (hex, bits) = hex_info(&id_bytes);
+
+    Some(IDInfo {
+        id_type: "Misskey ID".to_string(),
+        version: Some("meid".to_string()),
+        standard: id.to_string(),
+        parsed: Some("from hex".to_string()),
+        size: 96,
+        entropy: 48,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        hex,
+        bits,
+        color_map: Some(repeat_char('1', 1) + &repeat_char('3', 47) + &repeat_char('2', 48)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+pub fn parse_meidg(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    let hex_part = id.strip_prefix('g')?;
+    if hex_part.len() != 23 || !is_hex_lowercase(hex_part) {
+        return None;
+    }
+    let timestamp_raw = u64::from_str_radix(hex_part.get(0..11)?, 16).ok()?;
+    if !plausible(args, IdFormat::MisskeyMeidg, timestamp_raw) {
+        return None;
+    }
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw, epoch_ms(args, 0));
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+
+    Some(IDInfo {
+        id_type: "Misskey ID".to_string(),
+        version: Some("meidg".to_string()),
+        standard: id.to_string(),
+        parsed: Some("as ASCII, with hex parts".to_string()),
+        size,
+        entropy: 48,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        hex,
+        bits,
+        color_map: Some(repeat_char('1', 8) + &repeat_char('3', 88) + &repeat_char('2', 96)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+pub fn parse_misskey_objectid(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if id.len() != 24 || !is_hex_lowercase(id) {
+        return None;
+    }
+    let timestamp_raw = u64::from_str_radix(id.get(0..8)?, 16).ok()?;
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw * 1000, epoch_ms(args, 0));
+    let id_bytes = hex::decode(id).ok()?;
+    let (hex, bits) = hex_info(&id_bytes);
+
+    Some(IDInfo {
+        id_type: "Misskey ID".to_string(),
+        version: Some("objectid".to_string()),
+        standard: id.to_string(),
+        parsed: Some("from hex".to_string()),
+        size: 96,
+        entropy: 64,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        hex,
+        bits,
+        color_map: Some(repeat_char('3', 32) + &repeat_char('2', 64)),
+        // Same shape as a MongoDB ObjectId, which is far more common:
+        high_confidence: false,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(id: &str) -> Args {
+        Args {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_aid() {
+        let id_info = parse_aid(&args("9jzd4e39aw")).unwrap();
+        assert_eq!(id_info.datetime.unwrap(), "2023-09-23T08:58:17.301Z");
+        assert_eq!(id_info.sequence, Some(392));
+    }
+
+    #[test]
+    fn test_parse_aidx() {
+        let id_info = parse_aidx(&args("9jzd4e390a1b0001")).unwrap();
+        assert_eq!(id_info.datetime.unwrap(), "2023-09-23T08:58:17.301Z");
+        assert_eq!(id_info.node1.unwrap(), "13007 (Node ID)");
+        assert_eq!(id_info.sequence, Some(1));
+    }
+
+    #[test]
+    fn test_parse_meid() {
+        let id_info = parse_meid(&args("818ac142d5550123456789ab")).unwrap();
+        assert_eq!(id_info.datetime.unwrap(), "2023-09-23T08:58:17.301Z");
+    }
+
+    #[test]
+    fn test_parse_meidg() {
+        let id_info = parse_meidg(&args("g18bcfe568000123456789ab")).unwrap();
+        assert_eq!(id_info.datetime.unwrap(), "2023-11-14T22:13:20.000Z");
+    }
+
+    #[test]
+    fn test_parse_misskey_objectid() {
+        let id_info = parse_misskey_objectid(&args("6553f1000123456789abcdef")).unwrap();
+        assert_eq!(id_info.datetime.unwrap(), "2023-11-14T22:13:20.000Z");
+    }
+
+    #[test]
+    fn test_implausible_dates() {
+        let args = Args {
+            id: "zzzzzzzzzz".to_string(),
+            ..Default::default()
+        };
+        assert!(parse_aid(&args).is_none());
+        assert!(
+            parse_aid(&Args {
+                force: Some(IdFormat::MisskeyAid),
+                ..args
+            })
+            .is_some()
+        );
+    }
+}
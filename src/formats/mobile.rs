@@ -0,0 +1,588 @@
+use sha1_smol::Sha1;
+use std::fmt::Write;
+
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::{factor_size_hex_bits_color_from_text, luhn_check_digit, repeat_char};
+
+const MNC_REGISTRY: &str = include_str!("mccmnc.tsv");
+
+/// Mobile Country Codes (E.212) with the country code used in ICCIDs (E.164, with the E.118 exceptions for the United States and Kazakhstan).
+const COUNTRIES: &[(&str, &str, &str)] = &[
+    ("001", "", "Test network"),
+    ("202", "30", "Greece"),
+    ("204", "31", "Netherlands"),
+    ("206", "32", "Belgium"),
+    ("208", "33", "France"),
+    ("212", "377", "Monaco"),
+    ("213", "376", "Andorra"),
+    ("214", "34", "Spain"),
+    ("216", "36", "Hungary"),
+    ("218", "387", "Bosnia and Herzegovina"),
+    ("219", "385", "Croatia"),
+    ("220", "381", "Serbia"),
+    ("221", "383", "Kosovo"),
+    ("222", "39", "Italy"),
+    ("226", "40", "Romania"),
+    ("228", "41", "Switzerland"),
+    ("230", "420", "Czech Republic"),
+    ("231", "421", "Slovakia"),
+    ("232", "43", "Austria"),
+    ("234", "44", "United Kingdom"),
+    ("235", "44", "United Kingdom"),
+    ("238", "45", "Denmark"),
+    ("240", "46", "Sweden"),
+    ("242", "47", "Norway"),
+    ("244", "358", "Finland"),
+    ("246", "370", "Lithuania"),
+    ("247", "371", "Latvia"),
+    ("248", "372", "Estonia"),
+    ("250", "7", "Russia"),
+    ("255", "380", "Ukraine"),
+    ("257", "375", "Belarus"),
+    ("259", "373", "Moldova"),
+    ("260", "48", "Poland"),
+    ("262", "49", "Germany"),
+    ("266", "350", "Gibraltar"),
+    ("268", "351", "Portugal"),
+    ("270", "352", "Luxembourg"),
+    ("272", "353", "Ireland"),
+    ("274", "354", "Iceland"),
+    ("276", "355", "Albania"),
+    ("278", "356", "Malta"),
+    ("280", "357", "Cyprus"),
+    ("282", "995", "Georgia"),
+    ("283", "374", "Armenia"),
+    ("284", "359", "Bulgaria"),
+    ("286", "90", "Turkey"),
+    ("288", "298", "Faroe Islands"),
+    ("290", "299", "Greenland"),
+    ("292", "378", "San Marino"),
+    ("293", "386", "Slovenia"),
+    ("294", "389", "North Macedonia"),
+    ("295", "423", "Liechtenstein"),
+    ("297", "382", "Montenegro"),
+    ("302", "302", "Canada"),
+    ("310", "01", "United States"),
+    ("311", "01", "United States"),
+    ("312", "01", "United States"),
+    ("313", "01", "United States"),
+    ("314", "01", "United States"),
+    ("315", "01", "United States"),
+    ("316", "01", "United States"),
+    ("334", "52", "Mexico"),
+    ("368", "53", "Cuba"),
+    ("372", "509", "Haiti"),
+    ("400", "994", "Azerbaijan"),
+    ("401", "997", "Kazakhstan"),
+    ("402", "975", "Bhutan"),
+    ("404", "91", "India"),
+    ("405", "91", "India"),
+    ("410", "92", "Pakistan"),
+    ("412", "93", "Afghanistan"),
+    ("413", "94", "Sri Lanka"),
+    ("414", "95", "Myanmar"),
+    ("415", "961", "Lebanon"),
+    ("416", "962", "Jordan"),
+    ("417", "963", "Syria"),
+    ("418", "964", "Iraq"),
+    ("419", "965", "Kuwait"),
+    ("420", "966", "Saudi Arabia"),
+    ("421", "967", "Yemen"),
+    ("422", "968", "Oman"),
+    ("424", "971", "United Arab Emirates"),
+    ("425", "972", "Israel"),
+    ("426", "973", "Bahrain"),
+    ("427", "974", "Qatar"),
+    ("428", "976", "Mongolia"),
+    ("429", "977", "Nepal"),
+    ("432", "98", "Iran"),
+    ("434", "998", "Uzbekistan"),
+    ("436", "992", "Tajikistan"),
+    ("437", "996", "Kyrgyzstan"),
+    ("438", "993", "Turkmenistan"),
+    ("440", "81", "Japan"),
+    ("441", "81", "Japan"),
+    ("450", "82", "South Korea"),
+    ("452", "84", "Vietnam"),
+    ("454", "852", "Hong Kong"),
+    ("455", "853", "Macau"),
+    ("456", "855", "Cambodia"),
+    ("457", "856", "Laos"),
+    ("460", "86", "China"),
+    ("466", "886", "Taiwan"),
+    ("470", "880", "Bangladesh"),
+    ("472", "960", "Maldives"),
+    ("502", "60", "Malaysia"),
+    ("505", "61", "Australia"),
+    ("510", "62", "Indonesia"),
+    ("514", "670", "Timor-Leste"),
+    ("515", "63", "Philippines"),
+    ("520", "66", "Thailand"),
+    ("525", "65", "Singapore"),
+    ("528", "673", "Brunei"),
+    ("530", "64", "New Zealand"),
+    ("537", "675", "Papua New Guinea"),
+    ("542", "679", "Fiji"),
+    ("602", "20", "Egypt"),
+    ("603", "213", "Algeria"),
+    ("604", "212", "Morocco"),
+    ("605", "216", "Tunisia"),
+    ("606", "218", "Libya"),
+    ("608", "221", "Senegal"),
+    ("612", "225", "Ivory Coast"),
+    ("617", "230", "Mauritius"),
+    ("620", "233", "Ghana"),
+    ("621", "234", "Nigeria"),
+    ("624", "237", "Cameroon"),
+    ("630", "243", "DR Congo"),
+    ("634", "249", "Sudan"),
+    ("635", "250", "Rwanda"),
+    ("636", "251", "Ethiopia"),
+    ("639", "254", "Kenya"),
+    ("640", "255", "Tanzania"),
+    ("641", "256", "Uganda"),
+    ("643", "258", "Mozambique"),
+    ("645", "260", "Zambia"),
+    ("646", "261", "Madagascar"),
+    ("648", "263", "Zimbabwe"),
+    ("649", "264", "Namibia"),
+    ("650", "265", "Malawi"),
+    ("652", "267", "Botswana"),
+    ("655", "27", "South Africa"),
+    ("704", "502", "Guatemala"),
+    ("706", "503", "El Salvador"),
+    ("708", "504", "Honduras"),
+    ("710", "505", "Nicaragua"),
+    ("712", "506", "Costa Rica"),
+    ("714", "507", "Panama"),
+    ("716", "51", "Peru"),
+    ("722", "54", "Argentina"),
+    ("724", "55", "Brazil"),
+    ("730", "56", "Chile"),
+    ("732", "57", "Colombia"),
+    ("734", "58", "Venezuela"),
+    ("736", "591", "Bolivia"),
+    ("740", "593", "Ecuador"),
+    ("744", "595", "Paraguay"),
+    ("748", "598", "Uruguay"),
+];
+
+/// E.164 country codes with 2 digits; "1" and "7" have 1, and all the others have 3.
+const TWO_DIGIT_COUNTRY_CODES: &[&str] = &[
+    "20", "27", "30", "31", "32", "33", "34", "36", "39", "40", "41", "43", "44", "45", "46", "47", "48", "49", "51", "52", "53", "54", "55", "56", "57", "58", "60", "61", "62", "63", "64", "65",
+    "66", "81", "82", "84", "86", "90", "91", "92", "93", "94", "95", "98",
+];
+
+/// Countries where MNCs have 3 digits; elsewhere they have 2.
+const THREE_DIGIT_MNC: &[&str] = &[
+    "302", "310", "311", "312", "313", "314", "315", "316", "334", "338", "342", "344", "346", "348", "354", "356", "358", "360", "365", "376", "708", "722", "732",
+];
+
+/// Reporting Body Identifier: the first two digits of a TAC name the body that allocated it.
+fn reporting_body(tac: &str) -> Option<&'static str> {
+    let body = match tac.get(0..2)? {
+        "00" => "Test IMEI",
+        "01" => "PTCRB",
+        "30" => "Iridium",
+        "33" => "DGPT",
+        "35" | "44" | "98" => "BABT",
+        "45" => "NTA",
+        "49" | "50" => "BZT ETS",
+        "51" => "Cetecom ICT",
+        "52" => "Cetecom",
+        "53" => "TÜV",
+        "54" => "Phoenix Test Lab",
+        "86" => "TAF",
+        "91" => "MSAI",
+        "99" => "GHA",
+        _ => return None,
+    };
+    Some(body)
+}
+
+fn lookup_operator(registry: &'static str, mcc: &str, mnc: &str) -> Option<&'static str> {
+    registry.lines().filter(|line| !line.starts_with('#')).find_map(|line| {
+        let mut fields = line.split('\t');
+        match (fields.next(), fields.next(), fields.next()) {
+            (Some(code), Some(network), Some(operator)) if code == mcc && network == mnc => Some(operator),
+            _ => None,
+        }
+    })
+}
+
+fn mcc_country(mcc: &str) -> Option<&'static str> {
+    COUNTRIES.iter().find(|(code, _, _)| *code == mcc).map(|(_, _, country)| *country)
+}
+
+fn describe_tac(tac: &str) -> String {
+    let body = reporting_body(tac).map_or(String::new(), |body| format!(", {}", body));
+    format!("{} (Type Allocation Code{})", tac, body)
+}
+
+pub fn parse_imei(args: &Args) -> Option<IDInfo> {
+    let no_dashes = args.id.replace("-", "");
+    if no_dashes.chars().count() != 15 || !imei::valid(&no_dashes) {
+        return None;
+    }
+    let id_int = no_dashes.trim().parse::<u128>().ok()?;
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&no_dashes);
+    let standard = format!("{}-{}-{}-{}", no_dashes.get(0..2)?, no_dashes.get(2..8)?, no_dashes.get(8..14)?, no_dashes.get(14..15)?);
+
+    Some(IDInfo {
+        id_type: "IMEI".to_string(),
+        standard,
+        integer: Some(id_int),
+        parsed: Some("as ASCII, no dashes".to_string()),
+        size,
+        node1: Some(describe_tac(no_dashes.get(0..8)?)),
+        node2: Some(format!("{} (Check Digit)", no_dashes.get(14..15)?)),
+        sequence: no_dashes.get(8..14)?.parse::<u128>().ok(),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 64) + &repeat_char('6', 48) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// IMEISV replaces the check digit with a 2-digit Software Version Number. Bare 16-digit numbers are
+/// only accepted when forced, as they are far more likely to be something else.
+pub fn parse_imeisv(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    let dashed = id.split('-').map(str::len).eq([2, 6, 6, 2]);
+    let digits = id.replace("-", "");
+    if digits.len() != 16 || !digits.chars().all(|c| c.is_ascii_digit()) || !(dashed || args.force == Some(IdFormat::Imeisv)) {
+        return None;
+    }
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&digits);
+
+    Some(IDInfo {
+        id_type: "IMEISV".to_string(),
+        standard: format!("{}-{}-{}-{}", digits.get(0..2)?, digits.get(2..8)?, digits.get(8..14)?, digits.get(14..16)?),
+        integer: digits.parse::<u128>().ok(),
+        parsed: Some("as ASCII, no dashes".to_string()),
+        size,
+        node1: Some(describe_tac(digits.get(0..8)?)),
+        node2: Some(format!("{} (Software Version Number)", digits.get(14..16)?)),
+        sequence: digits.get(8..14)?.parse::<u128>().ok(),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 64) + &repeat_char('6', 48) + &repeat_char('5', 16)),
+        high_confidence: dashed,
+        ..Default::default()
+    })
+}
+
+/// ICCID (E.118): "89" for telecommunications, a country code, an issuer identifier (often the MNC),
+/// the account number and a Luhn check digit.
+pub fn parse_iccid(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim().replace(" ", "").replace("-", "");
+    if !(18..=22).contains(&id.len()) || !id.starts_with("89") || !id.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (payload, check) = id.split_at(id.len() - 1);
+    if check.parse::<u32>().ok()? != luhn_check_digit(payload, 10)? {
+        return None;
+    }
+    let rest = id.get(2..)?;
+    // Codes are prefix-free, except for Canada's 302 inside Greece's 30:
+    let (country_code, countries) = ["302", "01", "997"]
+        .iter()
+        .chain(COUNTRIES.iter().map(|(_, code, _)| code).filter(|code| !code.is_empty()))
+        .find(|code| rest.starts_with(**code))
+        .map(|code| (*code, COUNTRIES.iter().filter(|(_, country_code, _)| country_code == code).collect::<Vec<_>>()))
+        .or_else(|| {
+            let len = match rest.get(..1)? {
+                "1" | "7" => 1,
+                _ if TWO_DIGIT_COUNTRY_CODES.contains(&rest.get(..2)?) => 2,
+                _ => 3,
+            };
+            Some((rest.get(..len)?, vec![]))
+        })?;
+    let after_country = rest.get(country_code.len()..)?;
+    let (issuer, operator) = [3, 2]
+        .iter()
+        .find_map(|len| {
+            let issuer = after_country.get(..*len)?;
+            countries
+                .iter()
+                .find_map(|(mcc, _, _)| lookup_operator(MNC_REGISTRY, mcc, issuer))
+                .map(|operator| (issuer, Some(operator)))
+        })
+        .or_else(|| Some((after_country.get(..2)?, None)))?;
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&id);
+    let country_name = countries.first().map_or("Unknown country", |(_, _, country)| country);
+    let issuer_end = 2 + country_code.len() + issuer.len();
+    let account = id.get(issuer_end..id.len() - 1)?;
+
+    Some(IDInfo {
+        id_type: "ICCID".to_string(),
+        standard: id.clone(),
+        integer: id.parse::<u128>().ok(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("89 (Telecommunications), {} ({})", country_code, country_name)),
+        node2: Some(match operator {
+            Some(operator) => format!("{} (Issuer: {})", issuer, operator),
+            None => format!("{} (Issuer identifier)", issuer),
+        }),
+        node3: Some(format!("{} (Check Digit)", check)),
+        sequence: account.parse::<u128>().ok(),
+        hex,
+        bits,
+        color_map: Some(repeat_char('1', 16) + &repeat_char('4', country_code.len() * 8) + &repeat_char('5', issuer.len() * 8) + &repeat_char('6', account.len() * 8) + &repeat_char('7', 8)),
+        high_confidence: !countries.is_empty(),
+        ..Default::default()
+    })
+}
+
+/// IMSI (E.212): 3-digit MCC, 2 or 3-digit MNC and the subscriber's MSIN, up to 15 digits. Unforced,
+/// only IMSIs of a network in the bundled table are recognised.
+pub fn parse_imsi(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if !(14..=15).contains(&id.len()) || !id.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let mcc = id.get(0..3)?;
+    let country = mcc_country(mcc);
+    let mnc_len = match (lookup_operator(MNC_REGISTRY, mcc, id.get(3..6)?), lookup_operator(MNC_REGISTRY, mcc, id.get(3..5)?)) {
+        (Some(_), _) => 3,
+        (None, Some(_)) => 2,
+        (None, None) if THREE_DIGIT_MNC.contains(&mcc) => 3,
+        (None, None) => 2,
+    };
+    let mnc = id.get(3..3 + mnc_len)?;
+    let operator = lookup_operator(MNC_REGISTRY, mcc, mnc);
+    if operator.is_none() && args.force != Some(IdFormat::Imsi) {
+        return None;
+    }
+    let msin = id.get(3 + mnc_len..)?;
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+
+    Some(IDInfo {
+        id_type: "IMSI".to_string(),
+        standard: id.to_string(),
+        integer: id.parse::<u128>().ok(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("{} (Mobile Country Code, {})", mcc, country.unwrap_or("Unknown"))),
+        node2: Some(format!("{} (Mobile Network Code, {})", mnc, operator.unwrap_or("Unknown"))),
+        node3: Some(format!("{} (Mobile Subscription Identification Number)", msin)),
+        sequence: msin.parse::<u128>().ok(),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 24) + &repeat_char('5', mnc_len * 8) + &repeat_char('6', msin.len() * 8)),
+        high_confidence: operator.is_some(),
+        ..Default::default()
+    })
+}
+
+/// The pseudo-ESN is 0x80 followed by the lowest 24 bits of the SHA-1 of the MEID.
+fn pseudo_esn(meid: u64) -> Option<u32> {
+    let digest = Sha1::from(meid.to_be_bytes().get(1..)?).digest().bytes();
+    let [.., a, b, c] = digest;
+    Some(u32::from_be_bytes([0x80, a, b, c]))
+}
+
+fn bits_of(bytes: &[u8]) -> Option<String> {
+    Some(bytes.iter().fold(String::new(), |mut output, c| {
+        let _ = write!(output, "{c:08b}");
+        output
+    }))
+}
+
+/// MEID (CDMA): 8-bit regional code, 24-bit manufacturer code and 24-bit serial number, as 14 hex digits
+/// (plus an optional check digit) or 18 decimal digits.
+pub fn parse_mobile_meid(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim().replace("-", "").replace(" ", "");
+    let forced = args.force == Some(IdFormat::Meid);
+    let (value, parsed) = match id.len() {
+        14 | 15 if id.chars().all(|c| c.is_ascii_hexdigit()) => {
+            // Regional codes from A0 to FF, so MEIDs never clash with IMEIs; unforced, only uppercase is recognised:
+            if !(forced || id.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())) {
+                return None;
+            }
+            let hex_digits = id.get(0..14)?;
+            if let Some(check) = id.get(14..15)
+                && u32::from_str_radix(check, 16).ok()? != luhn_check_digit(hex_digits, 16)?
+            {
+                return None;
+            }
+            (u64::from_str_radix(hex_digits, 16).ok()?, "from hex")
+        }
+        18 if id.chars().all(|c| c.is_ascii_digit()) => {
+            let high = id.get(0..10)?.parse::<u64>().ok().filter(|value| *value <= 0xffff_ffff)?;
+            let serial = id.get(10..18)?.parse::<u64>().ok().filter(|value| *value <= 0xff_ffff)?;
+            ((high << 24) | serial, "from decimal")
+        }
+        _ => return None,
+    };
+    if value >> 48 < 0xa0 {
+        return None;
+    }
+    let hex_form = format!("{:014X}", value);
+    let decimal_form = format!("{:010}{:08}", value >> 24, value & 0xff_ffff);
+    let bytes = value.to_be_bytes();
+    let meid_bytes = bytes.get(1..)?;
+
+    Some(IDInfo {
+        id_type: "MEID".to_string(),
+        standard: format!("{}{:X}", hex_form, luhn_check_digit(&hex_form, 16)?),
+        integer: Some(value as u128),
+        parsed: Some(parsed.to_string()),
+        size: 56,
+        node1: Some(format!("{:02X} (Regional code)", value >> 48)),
+        node2: Some(format!("{:06X} (Manufacturer code)", (value >> 24) & 0xff_ffff)),
+        node3: Some(format!("{} (Decimal form), {:08X} (Pseudo-ESN)", decimal_form, pseudo_esn(value)?)),
+        sequence: Some((value & 0xff_ffff) as u128),
+        hex: Some(hex::encode(meid_bytes)),
+        bits: bits_of(meid_bytes),
+        color_map: Some(repeat_char('1', 8) + &repeat_char('4', 24) + &repeat_char('6', 24)),
+        high_confidence: parsed == "from hex",
+        ..Default::default()
+    })
+}
+
+/// ESN (CDMA): 8-bit manufacturer code and 24-bit serial number, as 8 hex digits or 11 decimal digits.
+/// Only parsed when forced, as both forms are too generic to detect.
+pub fn parse_esn(args: &Args) -> Option<IDInfo> {
+    if args.force != Some(IdFormat::Esn) {
+        return None;
+    }
+    let id = args.id.trim();
+    let (value, parsed) = match id.len() {
+        8 => (u32::from_str_radix(id, 16).ok().filter(|_| id.chars().all(|c| c.is_ascii_hexdigit()))?, "from hex"),
+        11 if id.chars().all(|c| c.is_ascii_digit()) => {
+            let manufacturer = id.get(0..3)?.parse::<u32>().ok().filter(|value| *value <= 0xff)?;
+            let serial = id.get(3..11)?.parse::<u32>().ok().filter(|value| *value <= 0xff_ffff)?;
+            ((manufacturer << 24) | serial, "from decimal")
+        }
+        _ => return None,
+    };
+    let manufacturer = value >> 24;
+
+    Some(IDInfo {
+        id_type: "ESN".to_string(),
+        version: (manufacturer == 0x80).then(|| "Pseudo-ESN (derived from a MEID)".to_string()),
+        standard: format!("{:08X}", value),
+        integer: Some(value as u128),
+        parsed: Some(parsed.to_string()),
+        size: 32,
+        node1: Some(format!("{:02X} (Manufacturer code)", manufacturer)),
+        node3: Some(format!("{:03}{:08} (Decimal form)", manufacturer, value & 0xff_ffff)),
+        sequence: Some((value & 0xff_ffff) as u128),
+        hex: Some(hex::encode(value.to_be_bytes())),
+        bits: bits_of(&value.to_be_bytes()),
+        color_map: Some(repeat_char('4', 8) + &repeat_char('6', 24)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(id: &str) -> Args {
+        Args {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_describe_tac() {
+        assert_eq!(describe_tac("35588906"), "35588906 (Type Allocation Code, BABT)");
+        assert_eq!(describe_tac("86123456"), "86123456 (Type Allocation Code, TAF)");
+        assert_eq!(describe_tac("77123456"), "77123456 (Type Allocation Code)");
+    }
+
+    #[test]
+    fn test_parse_iccid() {
+        let id_info = parse_iccid(&args("8944100000000000000")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "89 (Telecommunications), 44 (United Kingdom)");
+        assert_eq!(id_info.node2.unwrap(), "10 (Issuer: O2)");
+        let id_info = parse_iccid(&args("89012600000000000003")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "89 (Telecommunications), 01 (United States)");
+        assert_eq!(id_info.node2.unwrap(), "260 (Issuer: T-Mobile)");
+        let id_info = parse_iccid(&Args {
+            force: Some(IdFormat::Iccid),
+            ..args("8972012345678901239")
+        })
+        .unwrap();
+        assert_eq!(id_info.node1.unwrap(), "89 (Telecommunications), 7 (Russia)");
+        assert_eq!(id_info.node2.unwrap(), "20 (Issuer: Tele2)");
+        let id_info = parse_iccid(&args("89999012345678901234")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "89 (Telecommunications), 999 (Unknown country)");
+        assert_eq!(id_info.node2.unwrap(), "01 (Issuer identifier)");
+        assert!(!id_info.high_confidence);
+        assert!(parse_iccid(&args("8944100000000000001")).is_none()); // Wrong check digit.
+    }
+
+    #[test]
+    fn test_parse_imsi() {
+        let id_info = parse_imsi(&args("310260123456789")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "310 (Mobile Country Code, United States)");
+        assert_eq!(id_info.node2.unwrap(), "260 (Mobile Network Code, T-Mobile)");
+        assert_eq!(id_info.node3.unwrap(), "123456789 (Mobile Subscription Identification Number)");
+        let id_info = parse_imsi(&args("234150123456789")).unwrap();
+        assert_eq!(id_info.node2.unwrap(), "15 (Mobile Network Code, Vodafone)");
+        assert!(parse_imsi(&args("234990123456789")).is_none());
+        let id_info = parse_imsi(&Args {
+            force: Some(IdFormat::Imsi),
+            ..args("234990123456789")
+        })
+        .unwrap();
+        assert_eq!(id_info.node2.unwrap(), "99 (Mobile Network Code, Unknown)");
+    }
+
+    #[test]
+    fn test_parse_imeisv() {
+        let id_info = parse_imeisv(&args("35-588906-014977-23")).unwrap();
+        assert_eq!(id_info.node2.unwrap(), "23 (Software Version Number)");
+        assert_eq!(id_info.sequence, Some(14977));
+        assert!(parse_imeisv(&args("3558890601497723")).is_none());
+        assert!(
+            parse_imeisv(&Args {
+                force: Some(IdFormat::Imeisv),
+                ..args("3558890601497723")
+            })
+            .is_some()
+        );
+    }
+
+    #[test]
+    fn test_parse_mobile_meid() {
+        let id_info = parse_mobile_meid(&args("A0000000002329")).unwrap();
+        assert_eq!(id_info.standard, "A00000000023299");
+        assert_eq!(id_info.node1.unwrap(), "A0 (Regional code)");
+        assert_eq!(id_info.node3.unwrap(), "268435456000009001 (Decimal form), 8051F1AB (Pseudo-ESN)");
+        assert_eq!(id_info.sequence, Some(0x2329));
+        let id_info = parse_mobile_meid(&Args {
+            force: Some(IdFormat::Meid),
+            ..args("268435456000009001")
+        })
+        .unwrap();
+        assert_eq!(id_info.standard, "A00000000023299");
+        assert!(parse_mobile_meid(&args("A00000000023291")).is_none()); // Wrong check digit.
+        assert!(parse_mobile_meid(&args("35588906014977")).is_none()); // IMEI range.
+    }
+
+    #[test]
+    fn test_parse_esn() {
+        let id_info = parse_esn(&Args {
+            force: Some(IdFormat::Esn),
+            ..args("8051F1AB")
+        })
+        .unwrap();
+        assert_eq!(id_info.version.unwrap(), "Pseudo-ESN (derived from a MEID)");
+        assert_eq!(id_info.node3.unwrap(), "12805370283 (Decimal form)");
+        let id_info = parse_esn(&Args {
+            force: Some(IdFormat::Esn),
+            ..args("12805370283")
+        })
+        .unwrap();
+        assert_eq!(id_info.standard, "8051F1AB");
+        assert!(parse_esn(&args("8051F1AB")).is_none());
+    }
+}
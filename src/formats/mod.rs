@@ -1,29 +1,39 @@
 pub mod asin;
+pub mod bank;
 pub mod bitcoin;
 pub mod breezeid;
+pub mod card;
 pub mod commerce;
 pub mod cuid;
+pub mod database;
 pub mod datadog;
 pub mod duns;
+pub mod epochs;
 pub mod ethereum;
 pub mod flake;
 pub mod gdocs;
 pub mod geo;
+pub mod guid;
 pub mod hash;
 pub mod hashid;
 pub mod iban;
 pub mod ipfs;
 pub mod isbn;
 pub mod ksuid;
+pub mod misskey;
+pub mod mobile;
 pub mod nano64;
 pub mod nanoid;
 pub mod network;
 pub mod nuid;
 pub mod objectid;
 pub mod orderlyid;
+pub mod oui;
+pub mod publishing;
 pub mod puid;
 pub mod pushid;
 pub mod scru;
+pub mod securities;
 pub mod slack;
 pub mod snowflake;
 pub mod snowid;
@@ -1,27 +1,149 @@
-use imei;
 use mac_address::MacAddress;
 use std::net::{Ipv4Addr, Ipv6Addr};
 use std::{fmt::Write, str::FromStr};
 
-use crate::schema::{Args, IDInfo};
-use crate::utils::{factor_size_hex_bits_color_from_text, repeat_char};
+use crate::formats::oui::{mac_address_kind, mac_vendor};
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::repeat_char;
 
-pub fn parse_ipv4(args: &Args) -> Option<IDInfo> {
-    let ip = Ipv4Addr::from_str(&args.id).ok()?;
-    let mut version: Option<String> = None;
-    if ip.is_loopback() {
-        version = Some("Loopback".to_string());
+/// Splits "address/prefix" (CIDR notation), validating the prefix length.
+fn split_cidr(id: &str, max_prefix: u32) -> Option<(&str, Option<u32>)> {
+    match id.split_once('/') {
+        Some((address, prefix)) => {
+            if prefix.is_empty() || !prefix.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            Some((address, Some(prefix.parse::<u32>().ok().filter(|p| *p <= max_prefix)?)))
+        }
+        None => Some((id, None)),
     }
-    if ip.is_private() {
-        version = Some("Private".to_string());
+}
+
+fn parse_port(port: &str) -> Option<u16> {
+    if port.is_empty() || !port.chars().all(|c| c.is_ascii_digit()) {
+        return None;
     }
+    port.parse::<u16>().ok()
+}
 
-    Some(IDInfo {
-        id_type: "IPv4 Address".to_string(),
-        version,
-        standard: args.id.clone(),
+/// Splits "host:port" (IPv4) or "[host]:port" (IPv6) socket addresses.
+fn split_port(id: &str, bracketed: bool) -> Option<(&str, Option<u16>)> {
+    if bracketed {
+        return match id.strip_prefix('[') {
+            Some(rest) => {
+                let (host, port) = rest.split_once(']')?;
+                match port.strip_prefix(':') {
+                    Some(port) => Some((host, Some(parse_port(port)?))),
+                    None if port.is_empty() => Some((host, None)),
+                    None => None,
+                }
+            }
+            None => Some((id, None)),
+        };
+    }
+    match id.split_once(':') {
+        Some((host, port)) => Some((host, Some(parse_port(port)?))),
+        None => Some((id, None)),
+    }
+}
+
+/// Parses the notations accepted by `inet_aton()`: 1 to 4 parts, each decimal, octal (leading "0") or hex ("0x"),
+/// the last part filling the remaining bytes. Returns the notation name when it's not plain dotted decimal.
+fn parse_ipv4_notation(text: &str) -> Option<(Ipv4Addr, Option<String>)> {
+    if let Ok(ip) = Ipv4Addr::from_str(text) {
+        return Some((ip, None));
+    }
+    let mut values: Vec<u64> = vec![];
+    let (mut has_octal, mut has_hex) = (false, false);
+    for part in text.split('.') {
+        let (digits, radix) = match part.strip_prefix("0x").or_else(|| part.strip_prefix("0X")) {
+            Some(digits) => (digits, 16),
+            None if part.len() > 1 && part.starts_with('0') => (part.get(1..)?, 8),
+            None => (part, 10),
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return None;
+        }
+        has_octal |= radix == 8;
+        has_hex |= radix == 16;
+        values.push(u64::from_str_radix(digits, radix).ok()?);
+    }
+    let (last, init) = values.split_last()?;
+    if values.len() > 4 || init.iter().any(|value| *value > 255) {
+        return None;
+    }
+    let last_bits = 8 * (5 - values.len() as u32);
+    if *last >= 1 << last_bits {
+        return None;
+    }
+    let value = (init.iter().fold(0, |acc, value| (acc << 8) | value) << last_bits) | last;
+    let radix = match (has_octal, has_hex) {
+        (false, false) => "decimal",
+        (true, false) => "octal",
+        (false, true) => "hex",
+        (true, true) => "mixed octal and hex",
+    };
+    let notation = match values.len() {
+        1 => format!("{} integer", radix),
+        4 => format!("dotted {}", radix),
+        parts => format!("shortened dotted {}, {} parts", radix, parts),
+    };
+    Some((Ipv4Addr::from_bits(u32::try_from(value).ok()?), Some(notation)))
+}
+
+/// Classifies an address with the IANA IPv4 Special-Purpose Address Registry.
+fn classify_ipv4(ip: &Ipv4Addr) -> Option<&'static str> {
+    let class = match ip.octets() {
+        [255, 255, 255, 255] => "Limited broadcast",
+        [0, ..] => "This network",
+        [10, ..] | [172, 16..=31, ..] | [192, 168, ..] => "Private",
+        [100, 64..=127, ..] => "Shared address space (CGNAT)",
+        [127, ..] => "Loopback",
+        [169, 254, ..] => "Link-local",
+        [192, 0, 0, _] => "IETF protocol assignments",
+        [192, 0, 2, _] | [198, 51, 100, _] | [203, 0, 113, _] => "Documentation",
+        [192, 88, 99, _] => "6to4 relay anycast (deprecated)",
+        [198, 18..=19, ..] => "Benchmarking",
+        [224..=239, ..] => "Multicast",
+        [240..=255, ..] => "Reserved",
+        _ => return None,
+    };
+    Some(class)
+}
+
+fn address_count(host_bits: u32) -> String {
+    match 1_u128.checked_shl(host_bits) {
+        Some(count) if host_bits < 64 => count.to_string(),
+        _ => format!("2^{}", host_bits),
+    }
+}
+
+pub fn parse_ipv4(args: &Args) -> Option<IDInfo> {
+    let (host, port) = split_port(args.id.trim(), false)?;
+    let (address, prefix) = split_cidr(host, 32)?;
+    let (ip, notation) = parse_ipv4_notation(address)?;
+    // Numbers, dates like "19.10.2023" and versions like "1.2" are valid IPv4 notations, but rarely meant as such:
+    if notation.is_some() && args.force != Some(IdFormat::Ipv4) {
+        return None;
+    }
+    let standard = match (&notation, port) {
+        (None, None) => args.id.clone(),
+        _ => {
+            let prefix = prefix.map_or(String::new(), |prefix| format!("/{}", prefix));
+            let port = port.map_or(String::new(), |port| format!(":{}", port));
+            format!("{}{}{}", ip, prefix, port)
+        }
+    };
+    let mut id_info = IDInfo {
+        id_type: if port.is_some() { "IPv4 Socket Address" } else { "IPv4 Address" }.to_string(),
+        version: classify_ipv4(&ip).map(str::to_string),
+        standard,
         integer: Some(ip.to_bits() as u128),
-        parsed: Some("from integer parts".to_string()),
+        parsed: Some(format!(
+            "from {}{}",
+            notation.as_deref().unwrap_or("integer parts"),
+            port.map_or(String::new(), |port| format!(", port {}", port))
+        )),
         size: 32,
         hex: Some(hex::encode(ip.to_bits().to_be_bytes())),
         bits: Some(ip.to_bits().to_be_bytes().iter().fold(String::new(), |mut output, c| {
@@ -29,31 +151,208 @@ pub fn parse_ipv4(args: &Args) -> Option<IDInfo> {
             output
         })),
         color_map: Some(repeat_char('0', 32)),
-        high_confidence: true,
+        high_confidence: notation.is_none(),
         ..Default::default()
-    })
+    };
+    if let Some(prefix) = prefix {
+        let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+        let network = Ipv4Addr::from_bits(ip.to_bits() & mask);
+        let broadcast = Ipv4Addr::from_bits(ip.to_bits() | !mask);
+        // Point-to-point /31 and single-host /32 ranges have no network or broadcast address (RFC 3021):
+        let (first, last, hosts) = match prefix {
+            31 | 32 => (network, broadcast, address_count(32 - prefix)),
+            _ => (
+                Ipv4Addr::from_bits(network.to_bits() + 1),
+                Ipv4Addr::from_bits(broadcast.to_bits() - 1),
+                ((1_u64 << (32 - prefix)) - 2).to_string(),
+            ),
+        };
+        let class = classify_ipv4(&network);
+        id_info.id_type = "IPv4 Network (CIDR)".to_string();
+        id_info.version = class.filter(|class| Some(*class) == classify_ipv4(&broadcast)).map(str::to_string);
+        id_info.node1 = Some(format!("{} - {} (Network - broadcast)", network, broadcast));
+        id_info.node2 = Some(format!("{} - {} (First - last host)", first, last));
+        id_info.node3 = Some(format!("{} hosts", hosts));
+        id_info.color_map = Some(repeat_char('4', prefix as usize) + &repeat_char('6', (32 - prefix) as usize));
+    }
+    Some(id_info)
+}
+
+fn ipv6_multicast_scope(scope: u16) -> &'static str {
+    match scope {
+        0x1 => "interface-local",
+        0x2 => "link-local",
+        0x3 => "realm-local",
+        0x4 => "admin-local",
+        0x5 => "site-local",
+        0x8 => "organization-local",
+        0xe => "global",
+        _ => "reserved",
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Ipv6Class {
+    Unspecified,
+    Loopback,
+    Ipv4Mapped,
+    Ipv4Compatible,
+    Nat64WellKnown,
+    Nat64LocalUse,
+    DiscardOnly,
+    Teredo,
+    Benchmarking,
+    Documentation,
+    Orchid,
+    OrchidV2,
+    SixToFour,
+    GlobalUnicast,
+    UniqueLocal,
+    LinkLocal,
+    SiteLocal,
+    Multicast(u16),
+}
+
+impl Ipv6Class {
+    fn name(&self) -> String {
+        match self {
+            Ipv6Class::Unspecified => "Unspecified".to_string(),
+            Ipv6Class::Loopback => "Loopback".to_string(),
+            Ipv6Class::Ipv4Mapped => "IPv4-mapped".to_string(),
+            Ipv6Class::Ipv4Compatible => "IPv4-compatible (deprecated)".to_string(),
+            Ipv6Class::Nat64WellKnown => "NAT64 (well-known prefix)".to_string(),
+            Ipv6Class::Nat64LocalUse => "NAT64 (local-use prefix)".to_string(),
+            Ipv6Class::DiscardOnly => "Discard-only".to_string(),
+            Ipv6Class::Teredo => "Teredo".to_string(),
+            Ipv6Class::Benchmarking => "Benchmarking".to_string(),
+            Ipv6Class::Documentation => "Documentation".to_string(),
+            Ipv6Class::Orchid => "ORCHID (deprecated)".to_string(),
+            Ipv6Class::OrchidV2 => "ORCHIDv2".to_string(),
+            Ipv6Class::SixToFour => "6to4".to_string(),
+            Ipv6Class::GlobalUnicast => "Global unicast".to_string(),
+            Ipv6Class::UniqueLocal => "Unique local (ULA)".to_string(),
+            Ipv6Class::LinkLocal => "Link-local unicast".to_string(),
+            Ipv6Class::SiteLocal => "Site-local unicast (deprecated)".to_string(),
+            Ipv6Class::Multicast(scope) => format!("Multicast ({} scope)", ipv6_multicast_scope(*scope)),
+        }
+    }
+}
+
+/// Classifies an address with the IANA IPv6 Special-Purpose Address Registry (and the main unicast blocks).
+fn classify_ipv6(ip: &Ipv6Addr) -> Option<Ipv6Class> {
+    let [s0, s1, s2, s3, s4, s5, _, _] = ip.segments();
+    let upper_96_zero = [s0, s1, s2, s3, s4, s5].iter().all(|s| *s == 0);
+    let class = match (s0, s1) {
+        _ if ip.is_unspecified() => Ipv6Class::Unspecified,
+        _ if ip.is_loopback() => Ipv6Class::Loopback,
+        _ if ip.to_ipv4_mapped().is_some() => Ipv6Class::Ipv4Mapped,
+        _ if upper_96_zero => Ipv6Class::Ipv4Compatible,
+        (0x0064, 0xff9b) if [s2, s3, s4, s5].iter().all(|s| *s == 0) => Ipv6Class::Nat64WellKnown,
+        (0x0064, 0xff9b) if s2 == 0x0001 => Ipv6Class::Nat64LocalUse,
+        (0x0100, 0) if [s2, s3].iter().all(|s| *s == 0) => Ipv6Class::DiscardOnly,
+        (0x2001, 0x0000) => Ipv6Class::Teredo,
+        (0x2001, 0x0002) if s2 == 0 => Ipv6Class::Benchmarking,
+        (0x2001, 0x0db8) => Ipv6Class::Documentation,
+        (0x2001, 0x0010..=0x001f) => Ipv6Class::Orchid,
+        (0x2001, 0x0020..=0x002f) => Ipv6Class::OrchidV2,
+        (0x2002, _) => Ipv6Class::SixToFour,
+        (0x3fff, 0x0000..=0x0fff) => Ipv6Class::Documentation,
+        (0x2000..=0x3fff, _) => Ipv6Class::GlobalUnicast,
+        (0xfc00..=0xfdff, _) => Ipv6Class::UniqueLocal,
+        (0xfe80..=0xfebf, _) => Ipv6Class::LinkLocal,
+        (0xfec0..=0xfeff, _) => Ipv6Class::SiteLocal,
+        (0xff00..=0xffff, _) => Ipv6Class::Multicast(s0 & 0x000f),
+        _ => return None,
+    };
+    Some(class)
+}
+
+/// The MAC address behind a modified EUI-64 interface ID ("ff:fe" in the middle, U/L bit flipped).
+fn eui64_mac(octets: &[u8; 16]) -> Option<[u8; 6]> {
+    let [.., i0, i1, i2, 0xff, 0xfe, i5, i6, i7] = *octets else {
+        return None;
+    };
+    Some([i0 ^ 0x02, i1, i2, i5, i6, i7])
 }
 
 pub fn parse_ipv6(args: &Args) -> Option<IDInfo> {
-    let ip = Ipv6Addr::from_str(&args.id).ok()?;
-    let mut version: Option<String> = None;
-    if ip.is_loopback() {
-        version = Some("Loopback".to_string());
+    let (host, port) = split_port(args.id.trim(), true)?;
+    let (address, prefix) = split_cidr(host, 128)?;
+    let ip = Ipv6Addr::from_str(address).ok()?;
+    let class = classify_ipv6(&ip);
+    let octets = ip.octets();
+    let [_, o1, o2, o3, o4, o5, o6, o7, o8, o9, o10, o11, o12, o13, o14, o15] = octets;
+    let last_ipv4 = Ipv4Addr::new(o12, o13, o14, o15);
+    let mut node1: Option<String> = None;
+    let mut node2: Option<String> = None;
+    let mut node3: Option<String> = None;
+    let mut color_map = repeat_char('0', 128);
+
+    match class {
+        Some(Ipv6Class::Ipv4Mapped | Ipv6Class::Ipv4Compatible | Ipv6Class::Nat64WellKnown | Ipv6Class::Nat64LocalUse) => {
+            node1 = Some(format!("{} (Embedded IPv4)", last_ipv4));
+            color_map = repeat_char('0', 96) + &repeat_char('4', 32);
+        }
+        Some(Ipv6Class::SixToFour) => {
+            node1 = Some(format!("{} (6to4 gateway IPv4)", Ipv4Addr::new(o2, o3, o4, o5)));
+            color_map = repeat_char('0', 16) + &repeat_char('4', 32) + &repeat_char('0', 80);
+        }
+        Some(Ipv6Class::Teredo) => {
+            // RFC 4380: the client's port and address are stored with all bits inverted.
+            let client_port = u16::from_be_bytes([o10, o11]) ^ 0xffff;
+            let client_ip = Ipv4Addr::new(!o12, !o13, !o14, !o15);
+            node1 = Some(format!("{} (Teredo server)", Ipv4Addr::new(o4, o5, o6, o7)));
+            node2 = Some(format!("{}:{} (Teredo client, flags: {:#06x})", client_ip, client_port, u16::from_be_bytes([o8, o9])));
+            color_map = repeat_char('0', 32) + &repeat_char('4', 32) + &repeat_char('0', 16) + &repeat_char('5', 48);
+        }
+        Some(Ipv6Class::UniqueLocal) => {
+            node1 = Some(format!("{} (Global ID)", hex::encode([o1, o2, o3, o4, o5])));
+            node2 = Some(format!("{} (Subnet ID)", hex::encode([o6, o7])));
+            color_map = repeat_char('0', 8) + &repeat_char('4', 40) + &repeat_char('5', 16) + &repeat_char('0', 64);
+        }
+        _ => {}
+    }
+    if matches!(class, Some(Ipv6Class::GlobalUnicast | Ipv6Class::UniqueLocal | Ipv6Class::LinkLocal | Ipv6Class::Documentation))
+        && let Some(mac) = eui64_mac(&octets)
+    {
+        let address = mac.iter().map(|c| format!("{c:02x}")).collect::<Vec<String>>().join(":");
+        node3 = Some(match mac_vendor(&mac) {
+            Some(vendor) => format!("{} (MAC from EUI-64, {})", address, vendor),
+            None => format!("{} (MAC from EUI-64)", address),
+        });
+        color_map = color_map.chars().take(64).collect::<String>() + &repeat_char('7', 64);
+    }
+
+    let mut id_type = if port.is_some() { "IPv6 Socket Address" } else { "IPv6 Address" }.to_string();
+    let mut class = class;
+    if let Some(prefix) = prefix {
+        let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+        let first = Ipv6Addr::from_bits(ip.to_bits() & mask);
+        let last = Ipv6Addr::from_bits(ip.to_bits() | !mask);
+        id_type = "IPv6 Network (CIDR)".to_string();
+        class = classify_ipv6(&first).filter(|class| Some(*class) == classify_ipv6(&last));
+        node1 = Some(format!("{} - {} (First - last address)", first, last));
+        node2 = None;
+        node3 = Some(format!("{} addresses", address_count(128 - prefix)));
+        color_map = repeat_char('4', prefix as usize) + &repeat_char('6', (128 - prefix) as usize);
     }
 
     Some(IDInfo {
-        id_type: "IPv6 Address".to_string(),
-        version,
-        standard: args.id.clone().to_lowercase(),
+        id_type,
+        version: class.map(|class| class.name()),
+        standard: args.id.trim().to_lowercase(),
         integer: Some(ip.to_bits()),
-        parsed: Some("from hex parts".to_string()),
+        parsed: Some(format!("from hex parts{}", port.map_or(String::new(), |port| format!(", port {}", port)))),
         size: 128,
+        node1,
+        node2,
+        node3,
         hex: Some(hex::encode(ip.to_bits().to_be_bytes())),
         bits: Some(ip.to_bits().to_be_bytes().iter().fold(String::new(), |mut output, c| {
             let _ = write!(output, "{c:08b}");
             output
         })),
-        color_map: Some(repeat_char('0', 128)),
+        color_map: Some(color_map),
         high_confidence: true,
         ..Default::default()
     })
@@ -79,7 +378,11 @@ pub fn parse_mac(args: &Args) -> Option<IDInfo> {
         integer: Some(mac_int as u128),
         parsed: Some("from hex parts".to_string()),
         size: 48,
-        node1: Some(format!("{}, hex: {} (Manufacturer)", prefix, &mac_lower[..8])),
+        node1: Some(match mac_vendor(mac.bytes().as_slice()) {
+            Some(vendor) => format!("{}, hex: {} (Manufacturer: {})", prefix, &mac_lower[..8], vendor),
+            None => format!("{}, hex: {} (Manufacturer)", prefix, &mac_lower[..8]),
+        }),
+        node2: Some(mac_address_kind(mac.bytes().as_slice())),
         sequence: Some(sequence as u128),
         hex: Some(hex::encode(mac.bytes())),
         bits: Some(mac.bytes().iter().fold(String::new(), |mut output, c| {
@@ -92,28 +395,163 @@ pub fn parse_mac(args: &Args) -> Option<IDInfo> {
     })
 }
 
-pub fn parse_imei(args: &Args) -> Option<IDInfo> {
-    let no_dashes = args.id.replace("-", "");
-    if no_dashes.chars().count() != 15 || !imei::valid(&no_dashes) {
-        return None;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(id: &str) -> Args {
+        Args {
+            id: id.to_string(),
+            ..Default::default()
+        }
     }
-    let id_int = no_dashes.trim().parse::<u128>().ok()?;
-    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&no_dashes);
-    let standard = format!("{}-{}-{}-{}", &no_dashes[0..2], &no_dashes[2..8], &no_dashes[8..14], &no_dashes[14..15]);
 
-    Some(IDInfo {
-        id_type: "IMEI".to_string(),
-        standard,
-        integer: Some(id_int),
-        parsed: Some("as ASCII, no dashes".to_string()),
-        size,
-        node1: Some(format!("{} (Type Allocation Code)", &no_dashes[0..8])),
-        node2: Some(format!("{} (Check Digit)", &no_dashes[14..15])),
-        sequence: Some(no_dashes[8..14].parse::<u128>().unwrap()),
-        hex,
-        bits,
-        color_map: Some(repeat_char('4', 64) + &repeat_char('6', 48) + &repeat_char('5', 8)),
-        high_confidence: true,
-        ..Default::default()
-    })
+    #[test]
+    fn test_classify_ipv6() {
+        let classify = |id: &str| classify_ipv6(&Ipv6Addr::from_str(id).unwrap()).map(|class| class.name());
+        assert_eq!(classify("::"), Some("Unspecified".to_string()));
+        assert_eq!(classify("fd12:3456:789a:1::1"), Some("Unique local (ULA)".to_string()));
+        assert_eq!(classify("fe80::1"), Some("Link-local unicast".to_string()));
+        assert_eq!(classify("ff02::1"), Some("Multicast (link-local scope)".to_string()));
+        assert_eq!(classify("ff0e::101"), Some("Multicast (global scope)".to_string()));
+        assert_eq!(classify("2001:db8::1"), Some("Documentation".to_string()));
+        assert_eq!(classify("3fff:123::1"), Some("Documentation".to_string()));
+        assert_eq!(classify("100::1"), Some("Discard-only".to_string()));
+        assert_eq!(classify("2606:4700::1111"), Some("Global unicast".to_string()));
+        assert_eq!(classify("1::1"), None);
+    }
+
+    #[test]
+    fn test_parse_ipv6_embedded_ipv4() {
+        assert_eq!(parse_ipv6(&args("::ffff:192.0.2.1")).unwrap().node1.unwrap(), "192.0.2.1 (Embedded IPv4)");
+        assert_eq!(parse_ipv6(&args("64:ff9b::c000:201")).unwrap().node1.unwrap(), "192.0.2.1 (Embedded IPv4)");
+        assert_eq!(parse_ipv6(&args("2002:c000:201::1")).unwrap().node1.unwrap(), "192.0.2.1 (6to4 gateway IPv4)");
+    }
+
+    #[test]
+    fn test_classify_ipv4() {
+        let classify = |id: &str| classify_ipv4(&Ipv4Addr::from_str(id).unwrap());
+        assert_eq!(classify("100.64.1.1"), Some("Shared address space (CGNAT)"));
+        assert_eq!(classify("169.254.0.1"), Some("Link-local"));
+        assert_eq!(classify("198.19.255.1"), Some("Benchmarking"));
+        assert_eq!(classify("203.0.113.7"), Some("Documentation"));
+        assert_eq!(classify("239.255.255.250"), Some("Multicast"));
+        assert_eq!(classify("240.0.0.1"), Some("Reserved"));
+        assert_eq!(classify("255.255.255.255"), Some("Limited broadcast"));
+        assert_eq!(classify("172.32.0.1"), None);
+    }
+
+    #[test]
+    fn test_parse_ipv4_cidr() {
+        let id_info = parse_ipv4(&args("10.1.2.3/8")).unwrap();
+        assert_eq!(id_info.id_type, "IPv4 Network (CIDR)");
+        assert_eq!(id_info.version.unwrap(), "Private");
+        assert_eq!(id_info.node1.unwrap(), "10.0.0.0 - 10.255.255.255 (Network - broadcast)");
+        assert_eq!(id_info.node2.unwrap(), "10.0.0.1 - 10.255.255.254 (First - last host)");
+        assert_eq!(id_info.node3.unwrap(), "16777214 hosts");
+        assert_eq!(id_info.color_map.unwrap(), repeat_char('4', 8) + &repeat_char('6', 24));
+        assert_eq!(parse_ipv4(&args("192.0.2.7/31")).unwrap().node3.unwrap(), "2 hosts");
+        assert_eq!(parse_ipv4(&args("0.0.0.0/0")).unwrap().version, None);
+        for invalid in ["10.0.0.0/33", "10.0.0.0/", "10.0.0.0/+8"] {
+            assert!(
+                parse_ipv4(&Args {
+                    id: invalid.to_string(),
+                    ..Default::default()
+                })
+                .is_none()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_ipv4_notations() {
+        fn _assert(id: &str, standard: &str, parsed: &str) {
+            let id_info = parse_ipv4(&Args {
+                id: id.to_string(),
+                force: Some(IdFormat::Ipv4),
+                ..Default::default()
+            })
+            .unwrap();
+            assert_eq!(id_info.standard, standard);
+            assert_eq!(id_info.parsed.unwrap(), parsed);
+        }
+        _assert("3232235777", "192.168.1.1", "from decimal integer");
+        _assert("0xC0A80101", "192.168.1.1", "from hex integer");
+        _assert("030052000401", "192.168.1.1", "from octal integer");
+        _assert("0300.0250.01.01", "192.168.1.1", "from dotted octal");
+        _assert("0xc0.0250.1.1", "192.168.1.1", "from dotted mixed octal and hex");
+        _assert("127.1", "127.0.0.1", "from shortened dotted decimal, 2 parts");
+        _assert("10.1.300", "10.1.1.44", "from shortened dotted decimal, 3 parts");
+        _assert("10.0.0.1", "10.0.0.1", "from integer parts");
+        for invalid in ["256.0.0.1", "1.2.3.4.5", "4294967296", "1.16777216", "0x", "08.0.0.1", "+1.2.3.4"] {
+            assert!(
+                parse_ipv4(&Args {
+                    id: invalid.to_string(),
+                    force: Some(IdFormat::Ipv4),
+                    ..Default::default()
+                })
+                .is_none(),
+                "{invalid}"
+            );
+        }
+        // Without -f ipv4, only dotted quads are taken:
+        for other_notation in ["3232235777", "0xC0A80101", "0300.0250.01.01", "127.1", "19.10.2023", "127.1:80"] {
+            assert!(parse_ipv4(&args(other_notation)).is_none(), "{other_notation}");
+        }
+    }
+
+    #[test]
+    fn test_parse_socket_addresses() {
+        let id_info = parse_ipv4(&args("192.168.1.1:8080")).unwrap();
+        assert_eq!(id_info.id_type, "IPv4 Socket Address");
+        assert_eq!(id_info.parsed.unwrap(), "from integer parts, port 8080");
+        let id_info = parse_ipv4(&Args {
+            id: "127.1:80".to_string(),
+            force: Some(IdFormat::Ipv4),
+            ..Default::default()
+        });
+        assert_eq!(id_info.unwrap().standard, "127.0.0.1:80");
+        let id_info = parse_ipv6(&args("[::1]:443")).unwrap();
+        assert_eq!(id_info.id_type, "IPv6 Socket Address");
+        assert_eq!(id_info.version.unwrap(), "Loopback");
+        assert_eq!(id_info.parsed.unwrap(), "from hex parts, port 443");
+        for invalid in ["1.2.3.4:99999", "1.2.3.4:", "[::1]:", "[::1]x"] {
+            assert!(
+                parse_ipv4(&Args {
+                    id: invalid.to_string(),
+                    ..Default::default()
+                })
+                .or(parse_ipv6(&Args {
+                    id: invalid.to_string(),
+                    ..Default::default()
+                }))
+                .is_none(),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_ipv6_cidr() {
+        let id_info = parse_ipv6(&args("2001:db8::/32")).unwrap();
+        assert_eq!(id_info.version.unwrap(), "Documentation");
+        assert_eq!(id_info.node1.unwrap(), "2001:db8:: - 2001:db8:ffff:ffff:ffff:ffff:ffff:ffff (First - last address)");
+        assert_eq!(id_info.node3.unwrap(), "2^96 addresses");
+        assert_eq!(parse_ipv6(&args("fe80::/120")).unwrap().node3.unwrap(), "256 addresses");
+    }
+
+    #[test]
+    fn test_parse_ipv6_teredo() {
+        let id_info = parse_ipv6(&args("2001:0000:4136:e378:8000:63bf:3fff:fdd2")).unwrap();
+        assert_eq!(id_info.version.unwrap(), "Teredo");
+        assert_eq!(id_info.node1.unwrap(), "65.54.227.120 (Teredo server)");
+        assert_eq!(id_info.node2.unwrap(), "192.0.2.45:40000 (Teredo client, flags: 0x8000)");
+    }
+
+    #[test]
+    fn test_parse_ipv6_eui64() {
+        let id_info = parse_ipv6(&args("fe80::250:56ff:fec0:8")).unwrap();
+        assert_eq!(id_info.node3.unwrap(), "00:50:56:c0:00:08 (MAC from EUI-64, VMware, Inc.)");
+        assert!(parse_ipv6(&args("fe80::1")).unwrap().node3.is_none());
+    }
 }
@@ -0,0 +1,70 @@
+const OUI_REGISTRY: &str = include_str!("oui.tsv");
+
+/// Finds the organization of the longest matching prefix (MA-S, then MA-M, then MA-L) in a registry.
+fn lookup(registry: &'static str, mac_hex: &str) -> Option<&'static str> {
+    registry
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once('\t'))
+        .filter(|(prefix, _)| mac_hex.starts_with(prefix))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, organization)| organization)
+}
+
+/// Vendor of a MAC address, only for universally administered ones.
+pub fn mac_vendor(mac: &[u8]) -> Option<&'static str> {
+    if mac.first()? & 0x02 != 0 {
+        return None;
+    }
+    lookup(OUI_REGISTRY, &hex::encode_upper(mac))
+}
+
+/// Decodes the I/G (individual/group) and U/L (universal/local) bits of the first octet.
+pub fn mac_address_kind(mac: &[u8]) -> String {
+    if mac.iter().all(|byte| *byte == 0xff) {
+        return "Broadcast".to_string();
+    }
+    let first = mac.first().copied().unwrap_or_default();
+    let cast = if first & 0x01 == 0 { "Unicast" } else { "Multicast" };
+    let administered = if first & 0x02 == 0 { "universally administered" } else { "locally administered" };
+    format!("{}, {}", cast, administered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_longest_prefix() {
+        let registry = "# Comment\n70B3D5\tIEEE Registration Authority\n70B3D5123\tSome MA-S Vendor\n";
+        assert_eq!(lookup(registry, "70B3D5123456"), Some("Some MA-S Vendor"));
+        assert_eq!(lookup(registry, "70B3D5999999"), Some("IEEE Registration Authority"));
+        assert_eq!(lookup(registry, "000000000000"), None);
+    }
+
+    #[test]
+    fn test_bundled_registry() {
+        for line in OUI_REGISTRY.lines().filter(|line| !line.starts_with('#')) {
+            let (prefix, organization) = line.split_once('\t').expect(line);
+            assert!([6, 7, 9].contains(&prefix.len()), "{line}");
+            assert!(prefix.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()), "{line}");
+            assert!(!organization.is_empty(), "{line}");
+        }
+        assert_eq!(mac_vendor(&[0x70, 0xb3, 0xd5, 0x12, 0x34, 0x56]), Some("IEEE Registration Authority"));
+    }
+
+    #[test]
+    fn test_mac_vendor() {
+        assert_eq!(mac_vendor(&[0x00, 0x50, 0x56, 0x12, 0x34, 0x56]), Some("VMware, Inc."));
+        assert_eq!(mac_vendor(&[0xb8, 0x27, 0xeb, 0x12, 0x34, 0x56]), Some("Raspberry Pi Foundation"));
+        assert_eq!(mac_vendor(&[0x02, 0x50, 0x56, 0x12, 0x34, 0x56]), None); // Locally administered.
+    }
+
+    #[test]
+    fn test_mac_address_kind() {
+        assert_eq!(mac_address_kind(&[0x00, 0x50, 0x56, 0, 0, 0]), "Unicast, universally administered");
+        assert_eq!(mac_address_kind(&[0x01, 0x00, 0x5e, 0, 0, 1]), "Multicast, universally administered");
+        assert_eq!(mac_address_kind(&[0x52, 0x54, 0x00, 0, 0, 1]), "Unicast, locally administered");
+        assert_eq!(mac_address_kind(&[0xff; 6]), "Broadcast");
+    }
+}
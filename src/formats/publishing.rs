@@ -0,0 +1,332 @@
+use crate::country::country_name;
+use crate::formats::commerce::gtin_check_digit;
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::{factor_size_hex_bits_color_from_text, repeat_char};
+
+fn digits_of(text: &str) -> Option<Vec<u8>> {
+    text.bytes().map(|b| if b.is_ascii_digit() { Some(b - b'0') } else { None }).collect()
+}
+
+fn check_char(value: u32) -> String {
+    if value == 10 { "X".to_string() } else { value.to_string() }
+}
+
+/// ISSN: 7 digits and a mod-11 check digit (X for 10), written as "NNNN-NNNC". Undashed ones only when forced.
+pub fn parse_issn(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim().trim_start_matches("ISSN ").to_uppercase();
+    let dashed = id.len() == 9 && id.get(4..5)? == "-";
+    if !(dashed || (id.len() == 8 && args.force == Some(IdFormat::Issn))) {
+        return None;
+    }
+    let compact = id.replace("-", "");
+    let serial = compact.get(0..7)?;
+    let check = compact.get(7..8)?;
+    let mut sum = 0;
+    for (digit, weight) in digits_of(serial)?.iter().zip((2..=8).rev()) {
+        sum += *digit as u32 * weight;
+    }
+    if check != check_char((11 - sum % 11) % 11) {
+        return None;
+    }
+    let ean = format!("977{}00", serial);
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&compact);
+
+    Some(IDInfo {
+        id_type: "ISSN".to_string(),
+        standard: format!("{}-{}", compact.get(0..4)?, compact.get(4..8)?),
+        parsed: Some("as ASCII, no dashes".to_string()),
+        size,
+        node1: Some(format!("{} (Serial)", serial)),
+        node2: Some(format!("{} (Check Digit)", check)),
+        node3: Some(format!("{}{} (EAN-13, without issue number)", ean, gtin_check_digit(&digits_of(&ean)?))),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 56) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// Publisher digits of an ISMN, from the ranges after "979-0".
+fn ismn_publisher_len(digits: &str) -> Option<usize> {
+    let len = match digits.get(0..1)? {
+        "0" => 3,
+        "1" | "2" | "3" => 4,
+        "4" | "5" | "6" => 5,
+        "7" | "8" => 6,
+        _ => 7,
+    };
+    Some(len)
+}
+
+/// ISMN (printed music): "979-0", publisher, item and an EAN-13 check digit; older ones start with "M" instead of "979-0".
+pub fn parse_ismn(args: &Args) -> Option<IDInfo> {
+    let compact = args.id.trim().trim_start_matches("ISMN ").replace(['-', ' '], "");
+    let (digits, parsed) = match compact.strip_prefix('M') {
+        Some(rest) if rest.len() == 9 => (format!("9790{}", rest), "from 10-character form"),
+        _ if compact.len() == 13 && compact.starts_with("9790") => (compact.clone(), "as ASCII, no dashes"),
+        _ => return None,
+    };
+    let values = digits_of(&digits)?;
+    if values.last().copied()? != gtin_check_digit(values.get(0..12)?) {
+        return None;
+    }
+    let body = digits.get(4..12)?;
+    let publisher_len = ismn_publisher_len(body)?;
+    let publisher = body.get(..publisher_len)?;
+    let item = body.get(publisher_len..)?;
+    let check = digits.get(12..13)?;
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&digits);
+
+    Some(IDInfo {
+        id_type: "ISMN".to_string(),
+        standard: format!("979-0-{}-{}-{}", publisher, item, check),
+        integer: digits.parse::<u128>().ok(),
+        parsed: Some(parsed.to_string()),
+        size,
+        node1: Some(format!("{} (Publisher)", publisher)),
+        node2: Some(format!("{} (Check Digit)", check)),
+        node3: Some(format!("M-{}-{}-{} (10-character form)", publisher, item, check)),
+        sequence: item.parse::<u128>().ok(),
+        hex,
+        bits,
+        color_map: Some(repeat_char('0', 32) + &repeat_char('4', publisher.len() * 8) + &repeat_char('6', item.len() * 8) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// ISRC (sound recordings): country, registrant, year of reference and designation code. There is no check digit.
+pub fn parse_isrc(args: &Args) -> Option<IDInfo> {
+    let compact = args.id.trim().trim_start_matches("ISRC ").replace('-', "");
+    if compact.len() != 12 {
+        return None;
+    }
+    let country = compact.get(0..2)?;
+    let registrant = compact.get(2..5)?;
+    let year = compact.get(5..7)?;
+    let designation = compact.get(7..12)?;
+    if !country.chars().all(|c| c.is_ascii_uppercase()) || !registrant.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()) || !compact.get(5..12)?.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let name = match country {
+        "QM" | "QT" | "QZ" => "United States",
+        "ZZ" => "International ISRC Agency",
+        _ => country_name(country),
+    };
+    if name == "Unknown" && args.force != Some(IdFormat::Isrc) {
+        return None;
+    }
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&compact);
+
+    Some(IDInfo {
+        id_type: "ISRC".to_string(),
+        version: Some(format!("{} ({})", country, name)),
+        standard: format!("{}-{}-{}-{}", country, registrant, year, designation),
+        parsed: Some("as ASCII, no dashes".to_string()),
+        size,
+        node1: Some(format!("{} (Registrant)", registrant)),
+        node2: Some(format!("{} (Year of reference)", year)),
+        sequence: designation.parse::<u128>().ok(),
+        hex,
+        bits,
+        color_map: Some(repeat_char('1', 16) + &repeat_char('4', 24) + &repeat_char('5', 16) + &repeat_char('6', 40)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// ISWC (musical works): "T", 9 digits and a check digit, written as "T-034.524.680-1".
+pub fn parse_iswc(args: &Args) -> Option<IDInfo> {
+    let compact = args.id.trim().trim_start_matches("ISWC ").replace(['-', '.'], "");
+    let digits = compact.strip_prefix('T').filter(|digits| digits.len() == 10)?;
+    let values = digits_of(digits)?;
+    let mut sum = 1;
+    for (index, value) in values.iter().take(9).enumerate() {
+        sum += *value as u32 * (index as u32 + 1);
+    }
+    let check = *values.last()? as u32;
+    if check != (10 - sum % 10) % 10 {
+        return None;
+    }
+    let work = digits.get(0..9)?;
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&compact);
+
+    Some(IDInfo {
+        id_type: "ISWC".to_string(),
+        standard: format!("T-{}.{}.{}-{}", work.get(0..3)?, work.get(3..6)?, work.get(6..9)?, check),
+        parsed: Some("as ASCII, no separators".to_string()),
+        size,
+        node1: Some(format!("{} (Work)", work)),
+        node2: Some(format!("{} (Check Digit)", check)),
+        sequence: work.parse::<u128>().ok(),
+        hex,
+        bits,
+        color_map: Some(repeat_char('0', 8) + &repeat_char('6', 72) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// ORCID iDs are ISNIs from the blocks 0000-0001-5000-0007 to 0000-0003-5000-0001 and 0009-0000-0000-0000 to 0009-0010-0000-0000.
+fn is_orcid(number: u64) -> bool {
+    (15_000_000..=35_000_000).contains(&number) || (900_000_000_000..=900_100_000_000).contains(&number)
+}
+
+/// ISNI and ORCID: 15 digits and an ISO 7064 mod 11-2 check character. Unforced, they need their
+/// usual 4-digit groups or an "orcid.org" or "ISNI" prefix.
+pub fn parse_isni(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    let (rest, prefixed) = match ["https://orcid.org/", "http://orcid.org/", "orcid.org/", "ISNI "].iter().find_map(|prefix| id.strip_prefix(prefix)) {
+        Some(rest) => (rest, true),
+        None => (id, false),
+    };
+    let grouped = rest.split(['-', ' ']).map(str::len).eq([4, 4, 4, 4]);
+    let compact = rest.replace(['-', ' '], "").to_uppercase();
+    if compact.len() != 16 || !(grouped || prefixed || args.force == Some(IdFormat::Isni)) {
+        return None;
+    }
+    let mut total = 0;
+    for value in digits_of(compact.get(0..15)?)? {
+        total = (total + value as u32) * 2;
+    }
+    let check = compact.get(15..16)?;
+    if check != check_char((12 - total % 11) % 11) {
+        return None;
+    }
+    let number = compact.get(0..15)?.parse::<u64>().ok()?;
+    let groups = compact.chars().collect::<Vec<_>>().chunks(4).map(|chunk| chunk.iter().collect::<String>()).collect::<Vec<_>>();
+    let (version, standard, url) = if is_orcid(number) {
+        ("ORCID iD", groups.join("-"), format!("https://orcid.org/{}", groups.join("-")))
+    } else {
+        ("ISNI", groups.join(" "), format!("https://isni.org/isni/{}", compact))
+    };
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&compact);
+
+    Some(IDInfo {
+        id_type: "ISNI".to_string(),
+        version: Some(version.to_string()),
+        standard,
+        parsed: Some("as ASCII, no separators".to_string()),
+        size,
+        node1: Some(url),
+        node2: Some(format!("{} (Check Digit)", check)),
+        sequence: Some(number as u128),
+        hex,
+        bits,
+        color_map: Some(repeat_char('6', 120) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// DOI: "10." directory indicator, registrant code (with optional subdivisions), "/" and a suffix chosen by the registrant.
+pub fn parse_doi(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    let doi = ["https://doi.org/", "http://doi.org/", "https://dx.doi.org/", "http://dx.doi.org/", "doi:", "DOI:"]
+        .iter()
+        .find_map(|prefix| id.strip_prefix(prefix))
+        .unwrap_or(id);
+    let (prefix, suffix) = doi.split_once('/')?;
+    let registrant = prefix.strip_prefix("10.")?;
+    let mut subdivisions = registrant.split('.');
+    // Registrant codes have 4 digits or more, which also keeps IPv4 ranges like "10.0.0.0/8" out:
+    if !subdivisions.next().is_some_and(|code| code.len() >= 4 && code.chars().all(|c| c.is_ascii_digit()))
+        || !subdivisions.all(|code| !code.is_empty() && code.chars().all(|c| c.is_ascii_digit()))
+        || suffix.is_empty()
+        || suffix.chars().any(char::is_whitespace)
+    {
+        return None;
+    }
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(doi);
+
+    Some(IDInfo {
+        id_type: "DOI".to_string(),
+        standard: doi.to_string(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("{} (Registrant)", registrant)),
+        node2: Some(format!("{} (Suffix)", suffix)),
+        node3: Some(format!("https://doi.org/{}", doi)),
+        hex,
+        bits,
+        color_map: Some(repeat_char('0', 24) + &repeat_char('4', registrant.len() * 8) + &repeat_char('0', 8) + &repeat_char('6', suffix.len() * 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(id: &str) -> Args {
+        Args {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_issn() {
+        let id_info = parse_issn(&args("0378-5955")).unwrap();
+        assert_eq!(id_info.node3.unwrap(), "9770378595002 (EAN-13, without issue number)");
+        assert!(parse_issn(&args("2049-3630")).is_some());
+        assert!(parse_issn(&args("0378-5954")).is_none());
+        assert!(parse_issn(&args("03785955")).is_none());
+        assert!(
+            parse_issn(&Args {
+                force: Some(IdFormat::Issn),
+                ..args("03785955")
+            })
+            .is_some()
+        );
+    }
+
+    #[test]
+    fn test_parse_ismn() {
+        let id_info = parse_ismn(&args("979-0-2600-0043-8")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "2600 (Publisher)");
+        assert_eq!(id_info.sequence, Some(43));
+        let id_info = parse_ismn(&args("M-2306-7118-7")).unwrap();
+        assert_eq!(id_info.standard, "979-0-2306-7118-7");
+        assert!(parse_ismn(&args("979-0-2600-0043-9")).is_none());
+    }
+
+    #[test]
+    fn test_parse_isrc() {
+        let id_info = parse_isrc(&args("USRC17607839")).unwrap();
+        assert_eq!(id_info.version.unwrap(), "US (United States)");
+        assert_eq!(id_info.standard, "US-RC1-76-07839");
+        assert_eq!(id_info.node2.unwrap(), "76 (Year of reference)");
+        assert!(parse_isrc(&args("US-RC1-76-0783A")).is_none());
+    }
+
+    #[test]
+    fn test_parse_iswc() {
+        let id_info = parse_iswc(&args("T-034.524.680-1")).unwrap();
+        assert_eq!(id_info.standard, "T-034.524.680-1");
+        assert!(parse_iswc(&args("T0345246801")).is_some());
+        assert!(parse_iswc(&args("T0345246802")).is_none());
+    }
+
+    #[test]
+    fn test_parse_isni() {
+        let id_info = parse_isni(&args("https://orcid.org/0000-0002-1825-0097")).unwrap();
+        assert_eq!(id_info.version.unwrap(), "ORCID iD");
+        assert_eq!(id_info.standard, "0000-0002-1825-0097");
+        let id_info = parse_isni(&args("0000 0001 2146 438X")).unwrap();
+        assert_eq!(id_info.version.unwrap(), "ISNI");
+        assert!(parse_isni(&args("0000 0001 2146 4380")).is_none());
+        assert!(parse_isni(&args("000000012146438X")).is_none());
+    }
+
+    #[test]
+    fn test_parse_doi() {
+        let id_info = parse_doi(&args("https://doi.org/10.1000/182")).unwrap();
+        assert_eq!(id_info.standard, "10.1000/182");
+        assert_eq!(id_info.node1.unwrap(), "1000 (Registrant)");
+        assert!(parse_doi(&args("10.1038.5/nphys1170")).is_some());
+        assert!(parse_doi(&args("10.0.0.0/8")).is_none());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::country::country_name;
+use crate::formats::iban::mod97;
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::{factor_size_hex_bits_color_from_text, luhn_check_digit, repeat_char};
+
+/// Digits keep their value and letters count from A = 10, as in all ISO securities identifiers.
+fn char_value(c: char) -> Option<u32> {
+    match c {
+        '0'..='9' | 'A'..='Z' => c.to_digit(36),
+        _ => None,
+    }
+}
+
+/// Letters expanded to two digits each (A = 10), as used by the ISIN and LEI checksums.
+fn expand_letters(text: &str) -> Option<String> {
+    text.chars().map(|c| char_value(c).map(|value| value.to_string())).collect()
+}
+
+/// "Modulus 10 double add double" of CUSIPs and FIGIs: every second value doubled, then all their digits added.
+fn double_add_double_check_digit(text: &str) -> Option<u32> {
+    let mut sum = 0;
+    for (index, c) in text.chars().enumerate() {
+        let value = match c {
+            '*' => 36,
+            '@' => 37,
+            '#' => 38,
+            _ => char_value(c)?,
+        };
+        let value = if index % 2 == 1 { value * 2 } else { value };
+        sum += value / 10 + value % 10;
+    }
+    Some((10 - sum % 10) % 10)
+}
+
+fn is_upper_alphanumeric(text: &str) -> bool {
+    text.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+}
+
+/// ISIN (ISO 6166): country, 9-character National Securities Identifying Number and a Luhn check digit over the letters expanded to digits.
+pub fn parse_isin(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if id.len() != 12 || !is_upper_alphanumeric(id) {
+        return None;
+    }
+    let country = id.get(0..2)?;
+    let nsin = id.get(2..11)?;
+    let check = id.get(11..12)?;
+    if !country.chars().all(|c| c.is_ascii_uppercase()) || check.parse::<u32>().ok()? != luhn_check_digit(&expand_letters(id.get(0..11)?)?, 10)? {
+        return None;
+    }
+    // Besides ISO 3166 countries, ISO 6166 uses "XS" for international securities and "EU" for European Union issues:
+    let name = match country {
+        "XS" => "International (Euroclear, Clearstream)",
+        "EU" => "European Union",
+        _ => country_name(country),
+    };
+    if name == "Unknown" && args.force != Some(IdFormat::Isin) {
+        return None;
+    }
+    let nsin_kind = match country {
+        "US" | "CA" => ", CUSIP",
+        "GB" | "IE" if nsin.starts_with("00") => ", SEDOL",
+        _ => "",
+    };
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+
+    Some(IDInfo {
+        id_type: "ISIN".to_string(),
+        version: Some(format!("{} ({})", country, name)),
+        standard: id.to_string(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("{} (National Securities Identifying Number{})", nsin, nsin_kind)),
+        node2: Some(format!("{} (Check Digit)", check)),
+        hex,
+        bits,
+        color_map: Some(repeat_char('1', 16) + &repeat_char('4', 72) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// CUSIP: 6-character issuer, 2-character issue and a check digit.
+pub fn parse_cusip(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if id.len() != 9 || !id.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase() || "*@#".contains(c)) {
+        return None;
+    }
+    let issuer = id.get(0..6)?;
+    let issue = id.get(6..8)?;
+    let check = id.get(8..9)?;
+    if check.parse::<u32>().ok()? != double_add_double_check_digit(id.get(0..8)?)? {
+        return None;
+    }
+    // Without a digit, it is more likely a word than a CUSIP:
+    if !id.chars().any(|c| c.is_ascii_digit()) && args.force != Some(IdFormat::Cusip) {
+        return None;
+    }
+    let version = match issuer.chars().next()? {
+        'A'..='Z' => "CINS (international)",
+        _ => "North America",
+    };
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+
+    Some(IDInfo {
+        id_type: "CUSIP".to_string(),
+        version: Some(version.to_string()),
+        standard: id.to_string(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("{} (Issuer)", issuer)),
+        node2: Some(format!("{} (Issue)", issue)),
+        node3: Some(format!("{} (Check Digit)", check)),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 48) + &repeat_char('6', 16) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// SEDOL (London Stock Exchange): 6 characters without vowels and a weighted check digit. Only parsed
+/// when forced, as 7 characters are too generic to detect.
+pub fn parse_sedol(args: &Args) -> Option<IDInfo> {
+    if args.force != Some(IdFormat::Sedol) {
+        return None;
+    }
+    let id = args.id.trim();
+    if id.len() != 7 || !is_upper_alphanumeric(id) || id.chars().any(|c| "AEIOU".contains(c)) {
+        return None;
+    }
+    let mut sum = 0;
+    for (c, weight) in id.chars().zip([1, 3, 1, 7, 3, 9]) {
+        sum += char_value(c)? * weight;
+    }
+    let check = id.get(6..7)?;
+    if check.parse::<u32>().ok()? != (10 - sum % 10) % 10 {
+        return None;
+    }
+    let isin = format!("GB00{}", id);
+    let isin_check = luhn_check_digit(&expand_letters(&isin)?, 10)?;
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+
+    Some(IDInfo {
+        id_type: "SEDOL".to_string(),
+        standard: id.to_string(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("{}{} (ISIN, if issued in the United Kingdom)", isin, isin_check)),
+        node2: Some(format!("{} (Check Digit)", check)),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 48) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// FIGI (Financial Instrument Global Identifier): 2-letter issuer prefix, "G", 8 characters without vowels and a check digit.
+pub fn parse_figi(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim();
+    if id.len() != 12 || !is_upper_alphanumeric(id) || id.chars().any(|c| "AEIOU".contains(c)) {
+        return None;
+    }
+    let prefix = id.get(0..2)?;
+    let check = id.get(11..12)?;
+    // Prefixes that would clash with ISINs are not assigned:
+    if !prefix.chars().all(|c| c.is_ascii_uppercase()) || ["BS", "BM", "GG", "GB", "GH", "KY", "VG"].contains(&prefix) || id.get(2..3)? != "G" {
+        return None;
+    }
+    if check.parse::<u32>().ok()? != double_add_double_check_digit(id.get(0..11)?)? {
+        return None;
+    }
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(id);
+    let provider = match prefix {
+        "BB" => "Certified Provider: Bloomberg",
+        _ => "Certified Provider",
+    };
+
+    Some(IDInfo {
+        id_type: "FIGI".to_string(),
+        standard: id.to_string(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("{} ({})", prefix, provider)),
+        node2: Some(format!("{} (Check Digit)", check)),
+        node3: Some(format!("{} (Instrument)", id.get(3..11)?)),
+        hex,
+        bits,
+        color_map: Some(repeat_char('1', 16) + &repeat_char('0', 8) + &repeat_char('4', 64) + &repeat_char('5', 8)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+/// LEI (ISO 17442): 4-character prefix of the issuing Local Operating Unit, 14-character entity part and 2 check digits (ISO 7064 mod 97-10).
+pub fn parse_lei(args: &Args) -> Option<IDInfo> {
+    let id = args.id.trim().replace(" ", "");
+    if id.len() != 20 || !is_upper_alphanumeric(&id) || !id.get(18..20)?.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    if mod97(&expand_letters(&id)?) != 1 {
+        return None;
+    }
+    let (size, hex, bits, _) = factor_size_hex_bits_color_from_text(&id);
+
+    Some(IDInfo {
+        id_type: "LEI".to_string(),
+        standard: id.clone(),
+        parsed: Some("as ASCII".to_string()),
+        size,
+        node1: Some(format!("{} (Local Operating Unit)", id.get(0..4)?)),
+        node2: Some(format!("{} (Check Digits)", id.get(18..20)?)),
+        node3: Some(format!("{} (Legal Entity)", id.get(4..18)?)),
+        hex,
+        bits,
+        color_map: Some(repeat_char('4', 32) + &repeat_char('7', 112) + &repeat_char('5', 16)),
+        high_confidence: true,
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(id: &str) -> Args {
+        Args {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_isin() {
+        let id_info = parse_isin(&args("US0378331005")).unwrap();
+        assert_eq!(id_info.version.unwrap(), "US (United States)");
+        assert_eq!(id_info.node1.unwrap(), "037833100 (National Securities Identifying Number, CUSIP)");
+        let id_info = parse_isin(&args("GB0002634946")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "000263494 (National Securities Identifying Number, SEDOL)");
+        assert!(parse_isin(&args("AU0000XVGZA3")).is_some());
+        assert!(parse_isin(&args("US0378331006")).is_none());
+    }
+
+    #[test]
+    fn test_parse_cusip() {
+        let id_info = parse_cusip(&args("037833100")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "037833 (Issuer)");
+        assert_eq!(id_info.node2.unwrap(), "10 (Issue)");
+        assert!(parse_cusip(&args("38259P508")).is_some());
+        assert!(parse_cusip(&args("38259P509")).is_none());
+    }
+
+    #[test]
+    fn test_parse_sedol() {
+        let id_info = parse_sedol(&Args {
+            force: Some(IdFormat::Sedol),
+            ..args("0263494")
+        })
+        .unwrap();
+        assert_eq!(id_info.node1.unwrap(), "GB0002634946 (ISIN, if issued in the United Kingdom)");
+        assert!(
+            parse_sedol(&Args {
+                force: Some(IdFormat::Sedol),
+                ..args("B0YBKJ7")
+            })
+            .is_some()
+        );
+        assert!(parse_sedol(&args("B0YBKJ7")).is_none());
+        assert!(
+            parse_sedol(&Args {
+                force: Some(IdFormat::Sedol),
+                ..args("B0YBKJ8")
+            })
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_figi() {
+        let id_info = parse_figi(&args("BBG000BLNNH6")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "BB (Certified Provider: Bloomberg)");
+        assert_eq!(id_info.node2.unwrap(), "6 (Check Digit)");
+        assert!(parse_figi(&args("BBG000BLNNH7")).is_none());
+    }
+
+    #[test]
+    fn test_parse_lei() {
+        let id_info = parse_lei(&args("5493001KJTIIGC8Y1R12")).unwrap();
+        assert_eq!(id_info.node1.unwrap(), "5493 (Local Operating Unit)");
+        assert_eq!(id_info.node3.unwrap(), "001KJTIIGC8Y1R (Legal Entity)");
+        assert!(parse_lei(&args("5493001KJTIIGC8Y1R13")).is_none());
+    }
+}
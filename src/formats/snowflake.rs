@@ -2,7 +2,7 @@ use base58::{FromBase58, ToBase58};
 use std::fmt::Write;
 
 use crate::schema::{Args, IDInfo, IdFormat};
-use crate::utils::{bits64, epoch_ms, milliseconds_to_seconds_and_iso8601, repeat_char};
+use crate::utils::{bits64, epoch_ms, milliseconds_to_seconds_and_iso8601, now, repeat_char};
 
 #[derive(Debug)]
 pub struct SnowflakeAnnotation {
@@ -243,29 +243,221 @@ pub fn annotate_simpleflake(args: &Args) -> SnowflakeAnnotation {
     }
 }
 
+pub fn annotate_sony_v2(args: &Args) -> SnowflakeAnnotation {
+    let Some(id_int) = args.id.trim().parse::<u64>().ok() else {
+        return SnowflakeAnnotation::default();
+    };
+    let timestamp_raw = bits64(id_int, 1, 39);
+    let sequence = bits64(id_int, 40, 8);
+    let machine_id = bits64(id_int, 48, 16);
+    let time_unit = args.time_unit.unwrap_or(10);
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw.saturating_mul(time_unit), epoch_ms(args, 1735689600000));
+    SnowflakeAnnotation {
+        version: Some(format!("Sony v2 ({} ms time unit)", time_unit)),
+        custom_string: None,
+        entropy: 0,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        node1: Some(format!("{} (Machine ID)", machine_id)),
+        node2: None,
+        sequence: Some(sequence as u128),
+        color_map: Some(repeat_char('0', 1) + &repeat_char('3', 39) + &repeat_char('6', 8) + &repeat_char('4', 16)),
+    }
+}
+
+pub fn annotate_baidu(args: &Args) -> SnowflakeAnnotation {
+    let Some(id_int) = args.id.trim().parse::<u64>().ok() else {
+        return SnowflakeAnnotation::default();
+    };
+    let timestamp_raw = bits64(id_int, 1, 28);
+    let worker_id = bits64(id_int, 29, 22);
+    let sequence = bits64(id_int, 51, 13);
+    // Default epoch is "2016-05-20" in the server's time zone, usually China Standard Time:
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw * 1000, epoch_ms(args, 1463673600000));
+    SnowflakeAnnotation {
+        version: Some("Baidu UidGenerator".to_string()),
+        custom_string: None,
+        entropy: 0,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        node1: Some(format!("{} (Worker ID)", worker_id)),
+        node2: None,
+        sequence: Some(sequence as u128),
+        color_map: Some(repeat_char('0', 1) + &repeat_char('3', 28) + &repeat_char('4', 22) + &repeat_char('6', 13)),
+    }
+}
+
+pub fn annotate_leaf(args: &Args) -> SnowflakeAnnotation {
+    let Some(id_int) = args.id.trim().parse::<u64>().ok() else {
+        return SnowflakeAnnotation::default();
+    };
+    let timestamp_raw = bits64(id_int, 1, 41);
+    let worker_id = bits64(id_int, 42, 10);
+    let sequence = bits64(id_int, 52, 12);
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw, epoch_ms(args, 1288834974657));
+    SnowflakeAnnotation {
+        version: Some("Meituan Leaf".to_string()),
+        custom_string: None,
+        entropy: 0,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        node1: Some(format!("{} (Worker ID)", worker_id)),
+        node2: None,
+        sequence: Some(sequence as u128),
+        color_map: Some(repeat_char('0', 1) + &repeat_char('3', 41) + &repeat_char('4', 10) + &repeat_char('6', 12)),
+    }
+}
+
+pub fn annotate_yitter(args: &Args) -> SnowflakeAnnotation {
+    let Some(id_int) = args.id.trim().parse::<u64>().ok() else {
+        return SnowflakeAnnotation::default();
+    };
+    let timestamp_raw = bits64(id_int, 0, 52);
+    let worker_id = bits64(id_int, 52, 6);
+    let sequence = bits64(id_int, 58, 6);
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw, epoch_ms(args, 1582165202020));
+    SnowflakeAnnotation {
+        version: Some("Yitter".to_string()),
+        custom_string: None,
+        entropy: 0,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        node1: Some(format!("{} (Worker ID)", worker_id)),
+        node2: None,
+        sequence: Some(sequence as u128),
+        color_map: Some(repeat_char('3', 52) + &repeat_char('4', 6) + &repeat_char('6', 6)),
+    }
+}
+
+pub fn annotate_tiktok(args: &Args) -> SnowflakeAnnotation {
+    let Some(id_int) = args.id.trim().parse::<u64>().ok() else {
+        return SnowflakeAnnotation::default();
+    };
+    let timestamp_raw = bits64(id_int, 0, 32);
+    let (timestamp, datetime) = milliseconds_to_seconds_and_iso8601(timestamp_raw * 1000, epoch_ms(args, 0));
+    SnowflakeAnnotation {
+        version: Some("TikTok".to_string()),
+        custom_string: None,
+        entropy: 0,
+        datetime: Some(datetime),
+        timestamp: Some(timestamp),
+        node1: None,
+        node2: None,
+        sequence: None,
+        color_map: Some(repeat_char('3', 32) + &repeat_char('0', 32)),
+    }
+}
+
+type AnnotateFunction = fn(&Args) -> SnowflakeAnnotation;
+
+pub struct SnowflakeVariant {
+    pub id_format: IdFormat,
+    pub annotate: AnnotateFunction,
+    launched: i64,                    // Unix seconds when the variant started being used.
+    reserved_sign_bit: bool,          // Highest bit always zero.
+    max_node: u64,                    // Highest realistic worker/shard/machine ID.
+    max_sequence: u128,               // Highest sequence the layout allows.
+    same_layout_as: Option<IdFormat>, // Indistinguishable from an earlier variant, so not inferred on its own.
+}
+
+#[rustfmt::skip]
+pub const SNOWFLAKE_VARIANTS: [SnowflakeVariant; 15] = [
+    SnowflakeVariant { id_format: IdFormat::SfTwitter, annotate: annotate_twitter, launched: 1288834974, reserved_sign_bit: true, max_node: 1023, max_sequence: 4095, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfDiscord, annotate: annotate_discord, launched: 1420070400, reserved_sign_bit: false, max_node: 31, max_sequence: 4095, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfInstagram, annotate: annotate_instagram, launched: 1314220021, reserved_sign_bit: false, max_node: 4095, max_sequence: 1023, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfSony, annotate: annotate_sony, launched: 1409529600, reserved_sign_bit: true, max_node: 65535, max_sequence: 255, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfSpaceflake, annotate: annotate_spaceflake, launched: 1420070400, reserved_sign_bit: true, max_node: 31, max_sequence: 4095, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfLinkedin, annotate: annotate_linkedin, launched: 1388534400, reserved_sign_bit: true, max_node: 1023, max_sequence: 4095, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfMastodon, annotate: annotate_mastodon, launched: 1475280000, reserved_sign_bit: false, max_node: u64::MAX, max_sequence: u128::MAX, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfFrostflake, annotate: annotate_frostflake, launched: 1640995200, reserved_sign_bit: false, max_node: 2047, max_sequence: 2097151, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfFlakeid, annotate: annotate_flakeid, launched: 1356998400, reserved_sign_bit: false, max_node: 31, max_sequence: 4095, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfSimpleflake, annotate: annotate_simpleflake, launched: 1356998400, reserved_sign_bit: false, max_node: u64::MAX, max_sequence: u128::MAX, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfSonyV2, annotate: annotate_sony_v2, launched: 1735689600, reserved_sign_bit: true, max_node: 65535, max_sequence: 255, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfBaidu, annotate: annotate_baidu, launched: 1463673600, reserved_sign_bit: true, max_node: 1_000_000, max_sequence: 8191, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfLeaf, annotate: annotate_leaf, launched: 1488326400, reserved_sign_bit: true, max_node: 1023, max_sequence: 4095, same_layout_as: Some(IdFormat::SfTwitter) },
+    SnowflakeVariant { id_format: IdFormat::SfYitter, annotate: annotate_yitter, launched: 1582165202, reserved_sign_bit: false, max_node: 63, max_sequence: 63, same_layout_as: None },
+    SnowflakeVariant { id_format: IdFormat::SfTiktok, annotate: annotate_tiktok, launched: 1472688000, reserved_sign_bit: false, max_node: u64::MAX, max_sequence: u128::MAX, same_layout_as: None },
+];
+
+const OUTSIDE_LIFETIME_SCORE: f64 = 0.02;
+const UNREALISTIC_NODE_SCORE: f64 = 0.5;
+const HIGH_SEQUENCE_SCORE: f64 = 0.9;
+const MAX_AGE_PENALTY: f64 = 0.3;
+
 fn annotate_snowflake_variant(args: &Args) -> SnowflakeAnnotation {
-    match args.force {
-        Some(value) => {
-            #[allow(clippy::wildcard_enum_match_arm)]
-            let annotation: SnowflakeAnnotation = match value {
-                IdFormat::SfTwitter => annotate_twitter(args),
-                IdFormat::SfMastodon => annotate_mastodon(args),
-                IdFormat::SfDiscord => annotate_discord(args),
-                IdFormat::SfInstagram => annotate_instagram(args),
-                IdFormat::SfSony => annotate_sony(args),
-                IdFormat::SfSpaceflake => annotate_spaceflake(args),
-                IdFormat::SfLinkedin => annotate_linkedin(args),
-                IdFormat::SfFrostflake => annotate_frostflake(args),
-                IdFormat::SfFlakeid => annotate_flakeid(args),
-                IdFormat::SfSimpleflake => annotate_simpleflake(args),
-                _ => SnowflakeAnnotation::default(),
-            };
-            annotation
-        }
+    match SNOWFLAKE_VARIANTS.iter().find(|variant| args.force == Some(variant.id_format)) {
+        Some(variant) => (variant.annotate)(args),
         None => SnowflakeAnnotation::default(),
     }
 }
 
+fn leading_number(value: &str) -> Option<u64> {
+    value.split(' ').next()?.parse::<u64>().ok()
+}
+
+/// How plausible it is that the ID was generated by this variant, from 0 to 1.
+fn plausibility(args: &Args, id_int: u64, variant: &SnowflakeVariant, annotation: &SnowflakeAnnotation, now: f64) -> f64 {
+    if variant.reserved_sign_bit && bits64(id_int, 0, 1) == 1 {
+        return 0.0;
+    }
+    let launched = args.epoch.map_or(variant.launched as f64, |epoch| epoch as f64);
+    let timestamp = annotation.timestamp.as_deref().and_then(|value| value.parse::<f64>().ok()).unwrap_or_default();
+    // Newer IDs are more common than old ones, and none can come from the future (allowing a day of clock skew):
+    let mut score = if launched <= timestamp && timestamp <= now + 86_400.0 {
+        1.0 - MAX_AGE_PENALTY * ((now - timestamp) / (now - launched)).clamp(0.0, 1.0)
+    } else {
+        OUTSIDE_LIFETIME_SCORE
+    };
+    for node in [&annotation.node1, &annotation.node2].into_iter().flatten() {
+        if leading_number(node).is_some_and(|value| value > variant.max_node) {
+            score *= UNREALISTIC_NODE_SCORE;
+        }
+    }
+    // Most milliseconds only see a few IDs, so sequences rarely reach the upper half of their range:
+    if annotation.sequence.is_some_and(|sequence| sequence > variant.max_sequence / 2) {
+        score *= HIGH_SEQUENCE_SCORE;
+    }
+    score
+}
+
+/// Scores every variant at the reference time and returns the best annotation with its version
+/// showing the confidence and the runner-up. Variants that score the same are all named, as the
+/// ID can't tell them apart.
+fn infer_snowflake_variant(args: &Args, id_int: u64) -> SnowflakeAnnotation {
+    let now = now(args).timestamp_millis() as f64 / 1_000.0;
+    let mut scored: Vec<(f64, SnowflakeAnnotation)> = SNOWFLAKE_VARIANTS
+        .iter()
+        .filter(|variant| variant.same_layout_as.is_none())
+        .map(|variant| {
+            let annotation = (variant.annotate)(args);
+            (plausibility(args, id_int, variant, &annotation, now), annotation)
+        })
+        .collect();
+    let total: f64 = scored.iter().map(|(score, _)| score).sum();
+    if total <= 0.0 {
+        return SnowflakeAnnotation::default();
+    }
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let confidence = |score: f64| (score / total * 100.0).round();
+    let best_confidence = scored.first().map_or(0.0, |(score, _)| confidence(*score));
+    let tied = scored.iter().take_while(|(score, _)| confidence(*score) == best_confidence).count();
+    let best_name = scored
+        .iter()
+        .take(tied)
+        .map(|(_, annotation)| annotation.version.clone().unwrap_or_default())
+        .collect::<Vec<String>>()
+        .join(" or ");
+    let runner_up = scored
+        .get(tied)
+        .map(|(score, annotation)| format!(", runner-up: {} {}%", annotation.version.clone().unwrap_or_default(), confidence(*score)));
+    let Some((_, mut best)) = scored.into_iter().next() else {
+        return SnowflakeAnnotation::default();
+    };
+    let each = if tied > 1 { " each" } else { "" };
+    best.version = Some(format!("{} (inferred {}%{}{})", best_name, best_confidence, each, runner_up.unwrap_or_default()));
+    best
+}
+
 pub fn parse_snowflake(args: &Args) -> Option<IDInfo> {
     let mut parsed_args = args.clone();
 
@@ -291,7 +483,10 @@ pub fn parse_snowflake(args: &Args) -> Option<IDInfo> {
         Err(_) => return None,
     };
 
-    let annotation = annotate_snowflake_variant(&parsed_args);
+    let annotation = match parsed_args.force {
+        Some(_) => annotate_snowflake_variant(&parsed_args),
+        None => infer_snowflake_variant(&parsed_args, id_int),
+    };
 
     Some(IDInfo {
         id_type: "Snowflake".to_string(),
@@ -315,3 +510,94 @@ pub fn parse_snowflake(args: &Args) -> Option<IDInfo> {
         ..Default::default()
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2026-05-16T19:05:33Z, so the inferred confidence does not change as time goes by:
+    const NOW: i64 = 1778958333;
+
+    fn inferred_version(id: &str) -> String {
+        let args = Args {
+            id: id.to_string(),
+            now: chrono::DateTime::from_timestamp(NOW, 0),
+            ..Default::default()
+        };
+        infer_snowflake_variant(&args, id.parse().unwrap()).version.unwrap()
+    }
+
+    #[test]
+    fn test_infer_snowflake_variant() {
+        assert_eq!(inferred_version("2039815312720154892"), "Twitter (inferred 36%, runner-up: Instagram 31%)");
+        assert!(inferred_version("112277929257317646").starts_with("Mastodon (inferred "));
+        assert!(inferred_version("540226260526170119").starts_with("Sony (inferred "));
+        assert!(inferred_version("8120113273461424135").starts_with("Baidu UidGenerator (inferred "));
+        // Discord and Spaceflake share their epoch and layout:
+        assert!(inferred_version("1400000000000000000").starts_with("Discord or Spaceflake (inferred 23% each, runner-up: "));
+    }
+
+    #[test]
+    fn test_same_layout_is_not_inferred() {
+        assert!(!inferred_version("2039815312720154892").contains("Meituan Leaf"));
+        let args = Args {
+            id: "2039815312720154892".to_string(),
+            force: Some(IdFormat::SfLeaf),
+            ..Default::default()
+        };
+        assert_eq!(parse_snowflake(&args).unwrap().version.unwrap(), "Meituan Leaf");
+    }
+
+    #[test]
+    fn test_reserved_sign_bit() {
+        let args = Args {
+            id: (1u64 << 63 | 1000000000000000000).to_string(),
+            ..Default::default()
+        };
+        let twitter = &SNOWFLAKE_VARIANTS[0];
+        assert_eq!(plausibility(&args, 1u64 << 63 | 1000000000000000000, twitter, &annotate_twitter(&args), 1.8e9), 0.0);
+    }
+
+    #[test]
+    fn test_annotate_new_variants() {
+        fn args(id: &str) -> Args {
+            Args {
+                id: id.to_string(),
+                ..Default::default()
+            }
+        }
+        let sony_v2 = annotate_sony_v2(&args("24008867184967722"));
+        assert_eq!(sony_v2.datetime.unwrap(), "2025-06-15T15:06:40.000Z");
+        assert_eq!(sony_v2.node1.unwrap(), "42 (Machine ID)");
+        assert_eq!(sony_v2.sequence, Some(5));
+        let sony_v2_ms = annotate_sony_v2(&Args {
+            time_unit: Some(1),
+            ..args("24008867184967722")
+        });
+        assert_eq!(sony_v2_ms.version.unwrap(), "Sony v2 (1 ms time unit)");
+        let baidu = annotate_baidu(&args("8120113273461424135"));
+        assert_eq!(baidu.datetime.unwrap(), "2023-11-14T22:13:20.000Z");
+        assert_eq!(baidu.node1.unwrap(), "1234 (Worker ID)");
+        assert_eq!(baidu.sequence, Some(7));
+        let leaf = annotate_leaf(&args("1724551110456258569"));
+        assert_eq!(leaf.datetime.unwrap(), "2023-11-14T22:13:20.000Z");
+        assert_eq!(leaf.sequence, Some(9));
+        let yitter = annotate_yitter(&args("482651332526149"));
+        assert_eq!(yitter.datetime.unwrap(), "2023-11-14T22:13:20.000Z");
+        assert_eq!(yitter.node1.unwrap(), "1 (Worker ID)");
+        assert_eq!(yitter.sequence, Some(5));
+        let tiktok = annotate_tiktok(&args("7301444403323456789"));
+        assert_eq!(tiktok.datetime.unwrap(), "2023-11-14T22:13:20.000Z");
+    }
+
+    #[test]
+    fn test_forced_variant_is_not_inferred() {
+        let id_info = parse_snowflake(&Args {
+            id: "1777150623882019211".to_string(),
+            force: Some(IdFormat::SfDiscord),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(id_info.version.unwrap(), "Discord");
+    }
+}
@@ -1,13 +1,12 @@
 use std::fmt::Write;
-use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::schema::{Args, IDInfo};
-use crate::utils::{nanoseconds_to_iso8601, repeat_char};
+use crate::utils::{now, parse_scaled_decimal, repeat_char, unix_nanos_to_iso8601};
 
-const RECENT_DAYS_AGO: u64 = 365 * 10;
-const RECENT_DAYS_AHEAD: u64 = 365;
+const RECENT_DAYS_AGO: i128 = 365 * 10;
+const RECENT_DAYS_AHEAD: i128 = 365;
 
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 enum TimestampUnitAssumption {
     Seconds,
     Milliseconds,
@@ -17,88 +16,118 @@ enum TimestampUnitAssumption {
     Unknown,
 }
 
+impl TimestampUnitAssumption {
+    fn from_magnitude(integer_part: u128) -> Self {
+        match integer_part {
+            0..100_000_000_000 => TimestampUnitAssumption::Seconds,
+            100_000_000_000..100_000_000_000_000 => TimestampUnitAssumption::Milliseconds,
+            100_000_000_000_000..10_000_000_000_000_000 => TimestampUnitAssumption::Microseconds,
+            _ => TimestampUnitAssumption::Nanoseconds,
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            TimestampUnitAssumption::Seconds => "seconds",
+            TimestampUnitAssumption::Milliseconds => "milliseconds",
+            TimestampUnitAssumption::Microseconds => "microseconds",
+            TimestampUnitAssumption::Nanoseconds | TimestampUnitAssumption::Recent | TimestampUnitAssumption::Unknown => "nanoseconds",
+        }
+    }
+
+    fn nanos_scale(&self) -> u32 {
+        match self {
+            TimestampUnitAssumption::Seconds => 9,
+            TimestampUnitAssumption::Milliseconds => 6,
+            TimestampUnitAssumption::Microseconds => 3,
+            TimestampUnitAssumption::Nanoseconds | TimestampUnitAssumption::Recent | TimestampUnitAssumption::Unknown => 0,
+        }
+    }
+}
+
+/// Parses "[-]digits[.digits]" or "[-]0xHEX", returning the number scaled by `10^scale` and
+/// whether it had no fractional part.
+fn parse_signed_number(id: &str, scale: u32) -> Option<(i128, bool)> {
+    let (negative, unsigned) = match id.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, id),
+    };
+    if let Some(hex_digits) = unsigned.strip_prefix("0x").or_else(|| unsigned.strip_prefix("0X")) {
+        if hex_digits.is_empty() || !hex_digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let value = i128::from_str_radix(hex_digits, 16).ok()?.checked_mul(10_i128.checked_pow(scale)?)?;
+        return Some((if negative { -value } else { value }, true));
+    }
+    let integral = unsigned.split_once('.').is_none_or(|(_, fraction)| fraction.chars().all(|c| c == '0'));
+    Some((parse_scaled_decimal(id, scale)?, integral))
+}
+
+fn integer_part(id: &str) -> Option<u128> {
+    let unsigned = id.strip_prefix('-').unwrap_or(id);
+    match unsigned.strip_prefix("0x").or_else(|| unsigned.strip_prefix("0X")) {
+        Some(hex_digits) => u128::from_str_radix(hex_digits, 16).ok(),
+        None => unsigned.split('.').next()?.parse::<u128>().ok(),
+    }
+}
+
 fn parse_unix_core(args: &Args, assumption: TimestampUnitAssumption) -> Option<IDInfo> {
-    let id_int: u64 = args.id.trim().parse::<u64>().ok()?;
-    let (timestamp, datetime): (String, String);
-    let version: String;
-    let timestamp_ns: u64;
-
-    match assumption {
-        TimestampUnitAssumption::Unknown | TimestampUnitAssumption::Recent => {
-            match id_int {
-                0..100_000_000_000 => {
-                    timestamp_ns = id_int * 1_000_000_000;
-                    version = "Assuming seconds".to_string();
-                }
-                100_000_000_000..100_000_000_000_000 => {
-                    timestamp_ns = id_int * 1_000_000;
-                    version = "Assuming milliseconds".to_string();
-                }
-                100_000_000_000_000..10_000_000_000_000_000 => {
-                    timestamp_ns = id_int * 1_000;
-                    version = "Assuming microseconds".to_string();
-                }
-                10_000_000_000_000_000..u64::MAX | u64::MAX => {
-                    timestamp_ns = id_int;
-                    version = "Assuming nanoseconds".to_string();
-                }
-            }
-            (timestamp, datetime) = nanoseconds_to_iso8601(timestamp_ns);
-
-            if assumption == TimestampUnitAssumption::Recent {
-                let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos() as u64;
-                let start: u64 = now - (1_000_000_000 * 60 * 60 * 24 * RECENT_DAYS_AGO);
-                let end: u64 = now + (1_000_000_000 * 60 * 60 * 24 * RECENT_DAYS_AHEAD);
-                if !(start < timestamp_ns && timestamp_ns < end) {
-                    return None;
-                }
-            }
+    let id = args.id.trim();
+    let unit = match assumption {
+        TimestampUnitAssumption::Unknown | TimestampUnitAssumption::Recent => TimestampUnitAssumption::from_magnitude(integer_part(id)?),
+        TimestampUnitAssumption::Seconds | TimestampUnitAssumption::Milliseconds | TimestampUnitAssumption::Microseconds | TimestampUnitAssumption::Nanoseconds => assumption,
+    };
+    let (timestamp_ns, integral) = parse_signed_number(id, unit.nanos_scale())?;
+    let version = match assumption {
+        TimestampUnitAssumption::Unknown | TimestampUnitAssumption::Recent => format!("Assuming {}", unit.name()),
+        TimestampUnitAssumption::Seconds | TimestampUnitAssumption::Milliseconds | TimestampUnitAssumption::Microseconds | TimestampUnitAssumption::Nanoseconds => {
+            format!("As {}", unit.name())
         }
-        TimestampUnitAssumption::Seconds => match id_int.checked_mul(1_000_000_000) {
-            Some(value) => {
-                (timestamp, datetime) = nanoseconds_to_iso8601(value);
-                version = "As seconds".to_string();
-            }
-            None => return None,
-        },
-        TimestampUnitAssumption::Milliseconds => match id_int.checked_mul(1_000_000) {
-            Some(value) => {
-                (timestamp, datetime) = nanoseconds_to_iso8601(value);
-                version = "As milliseconds".to_string();
-            }
-            None => return None,
-        },
-        TimestampUnitAssumption::Microseconds => match id_int.checked_mul(1_000) {
-            Some(value) => {
-                (timestamp, datetime) = nanoseconds_to_iso8601(value);
-                version = "As microseconds".to_string();
-            }
-            None => return None,
-        },
-        TimestampUnitAssumption::Nanoseconds => {
-            version = "As nanoseconds".to_string();
-            (timestamp, datetime) = nanoseconds_to_iso8601(id_int);
+    };
+    let (timestamp, datetime) = unix_nanos_to_iso8601(timestamp_ns);
+    if datetime == "Invalid" {
+        return None;
+    }
+
+    if assumption == TimestampUnitAssumption::Recent {
+        let now = i128::from(now(args).timestamp_millis()) * 1_000_000;
+        let start = now - (1_000_000_000 * 60 * 60 * 24 * RECENT_DAYS_AGO);
+        let end = now + (1_000_000_000 * 60 * 60 * 24 * RECENT_DAYS_AHEAD);
+        if !(start < timestamp_ns && timestamp_ns < end) {
+            return None;
         }
     }
 
-    Some(IDInfo {
+    let mut id_info = IDInfo {
         id_type: "Unix timestamp".to_string(),
         version: Some(version),
         standard: args.id.to_string(),
-        integer: Some(id_int as u128),
-        size: 64,
-        parsed: Some("as integer".to_string()),
         datetime: Some(datetime),
         timestamp: Some(timestamp),
-        hex: Some(hex::encode(id_int.to_be_bytes())),
-        bits: Some(id_int.to_be_bytes().iter().fold(String::new(), |mut output, c| {
-            let _ = write!(output, "{c:08b}");
-            output
-        })),
-        color_map: Some(repeat_char('3', 64)),
         high_confidence: true,
         ..Default::default()
-    })
+    };
+    // Only whole numbers have a meaningful binary representation (two's complement when negative):
+    let raw_value = parse_signed_number(id, 0).map(|(value, _)| value);
+    if let (true, Some(value)) = (integral, raw_value) {
+        let bytes = match (u64::try_from(value), i64::try_from(value)) {
+            (Ok(unsigned), _) => {
+                id_info.integer = Some(unsigned as u128);
+                unsigned.to_be_bytes()
+            }
+            (Err(_), Ok(signed)) => signed.to_be_bytes(),
+            (Err(_), Err(_)) => return Some(id_info),
+        };
+        id_info.size = 64;
+        id_info.parsed = Some("as integer".to_string());
+        id_info.hex = Some(hex::encode(bytes));
+        id_info.bits = Some(bytes.iter().fold(String::new(), |mut output, c| {
+            let _ = write!(output, "{c:08b}");
+            output
+        }));
+        id_info.color_map = Some(repeat_char('3', 64));
+    }
+    Some(id_info)
 }
 
 pub fn parse_unix(args: &Args) -> Option<IDInfo> {
@@ -225,4 +254,63 @@ mod tests {
         assert!(parse_unix_us(&args).is_none());
         assert!(parse_unix_ns(&args).is_some()); // Don't overflow.
     }
+
+    #[test]
+    fn test_parse_unix_fractional() {
+        let result = parse_unix(&Args {
+            id: "1700000000.123456".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(result.version.unwrap(), "Assuming seconds".to_string());
+        assert_eq!(result.timestamp.unwrap(), "1700000000.123456000".to_string());
+        assert_eq!(result.datetime.unwrap(), "2023-11-14T22:13:20.123456000Z".to_string());
+        assert!(result.bits.is_none());
+
+        let result = parse_unix_ms(&Args {
+            id: "1700000000123.4567".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(result.timestamp.unwrap(), "1700000000.123456700".to_string());
+    }
+
+    #[test]
+    fn test_parse_unix_negative() {
+        let result = parse_unix(&Args {
+            id: "-86400".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(result.timestamp.unwrap(), "-86400.000000000".to_string());
+        assert_eq!(result.datetime.unwrap(), "1969-12-31T00:00:00.000000000Z".to_string());
+        assert_eq!(result.hex.unwrap(), "fffffffffffeae80".to_string());
+        assert!(result.integer.is_none());
+    }
+
+    #[test]
+    fn test_parse_unix_hex() {
+        let result = parse_unix(&Args {
+            id: "0x6553f100".to_string(),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(result.version.unwrap(), "Assuming seconds".to_string());
+        assert_eq!(result.timestamp.unwrap(), "1700000000.000000000".to_string());
+        assert_eq!(result.integer, Some(1700000000));
+        assert!(
+            parse_unix(&Args {
+                id: "0x".to_string(),
+                ..Default::default()
+            })
+            .is_none()
+        );
+        assert!(
+            parse_unix(&Args {
+                id: "1.2.3".to_string(),
+                ..Default::default()
+            })
+            .is_none()
+        );
+    }
 }
@@ -1,17 +1,133 @@
 use base64::{Engine as _, engine::general_purpose::URL_SAFE, engine::general_purpose::URL_SAFE_NO_PAD};
+use md5::{Digest, Md5};
 use short_uuid::{CustomTranslator, ShortUuidCustom};
 use std::fmt::Write;
-use uuid::{Uuid, Variant};
+use uuid::{Builder, Uuid, Variant};
 use uuid25::Uuid25;
 
-use crate::schema::{Args, IDInfo};
-use crate::utils::{epoch_ms, milliseconds_to_seconds_and_iso8601};
+use crate::formats::oui::mac_vendor;
+use crate::schema::{Args, IDInfo, IdFormat};
+use crate::utils::{epoch_ms, milliseconds_to_seconds_and_iso8601, unix_nanos_to_iso8601};
 
 pub const SHORT_UUID_ALPHABET: &str = "23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
 pub const COLOR_MAP_UUID_GENERIC: &str = "22222222222222222222222222222222222222222222222211112222222222220022222222222222222222222222222222222222222222222222222222222222";
 pub const COLOR_MAP_UUID_NO_RFC: &str = "22222222222222222222222222222222222222222222222222222222222222220022222222222222222222222222222222222222222222222222222222222222";
-pub const COLOR_MAP_UUID_16: &str = "33333333333333333333333333333333333333333333333311113333333333330066666666666666444444444444444444444444444444444444444444444444";
+pub const COLOR_MAP_UUID_16: &str = "33333333333333333333333333333333333333333333333311113333333333335566666666666666444444444444444444444444444444444444444444444444";
+pub const COLOR_MAP_UUID_2: &str = "55555555555555555555555555555555333333333333333311113333333333330066666677777777444444444444444444444444444444444444444444444444";
 pub const COLOR_MAP_UUID_7: &str = "33333333333333333333333333333333333333333333333311112222222222220022222222222222222222222222222222222222222222222222222222222222";
+pub const COLOR_MAP_UUID_7_SUB_MS: &str = "33333333333333333333333333333333333333333333333311113333333333330022222222222222222222222222222222222222222222222222222222222222";
+pub const COLOR_MAP_UUID_7_COUNTER: &str = "33333333333333333333333333333333333333333333333311116666666666660022222222222222222222222222222222222222222222222222222222222222";
+
+const GREGORIAN_TO_UNIX_100NS: u64 = 0x01B2_1DD2_1381_4000;
+
+/// DCE security UUIDs replace the lowest 32 bits of the timestamp with the local ID,
+/// so only a lower bound with a ~7 minute resolution can be recovered, shown to the minute.
+fn dce_timestamp_ms(uuid: &Uuid) -> Option<u64> {
+    if uuid.get_version_num() != 2 {
+        return None;
+    }
+    let [_, _, _, _, time_mid_1, time_mid_2, time_high_1, time_high_2, ..] = *uuid.as_bytes();
+    let time_high = u64::from(u16::from_be_bytes([time_high_1 & 0x0f, time_high_2]));
+    let time_mid = u64::from(u16::from_be_bytes([time_mid_1, time_mid_2]));
+    let gregorian_100ns = (time_high << 48) | (time_mid << 32);
+    let unix_ms = gregorian_100ns.checked_sub(GREGORIAN_TO_UNIX_100NS)? / 10_000;
+    Some(unix_ms - unix_ms % 60_000)
+}
+
+/// RFC variant with one of the defined versions, as opposed to random bytes or a byte-swapped UUID.
+pub(crate) fn has_valid_version(uuid: &Uuid) -> bool {
+    uuid.get_variant() == Variant::RFC4122 && (1..=8).contains(&uuid.get_version_num())
+}
+
+/// The multicast bit is never set in a real hardware address, so RFC-4122 uses it to mark random node IDs.
+fn format_node(node: &[u8]) -> String {
+    let address = node.iter().map(|c| format!("{c:02x}")).collect::<Vec<String>>().join(":");
+    match (node.first(), mac_vendor(node)) {
+        (Some(first), _) if first & 0x01 == 1 => format!("{} (Random node ID, multicast bit set)", address),
+        (Some(first), _) if first & 0x02 == 2 => format!("{} (Locally administered MAC address)", address),
+        (_, Some(vendor)) => format!("{} (MAC address, {})", address, vendor),
+        (_, None) => format!("{} (MAC address)", address),
+    }
+}
+
+/// How a UUIDv7 uses its 12-bit `rand_a` field (RFC-9562, section 6.2).
+#[derive(Clone, Copy, PartialEq)]
+enum RandAMethod {
+    Random,
+    SubMillisecond,
+    Counter,
+}
+
+/// Counters that restart every millisecond stay below this, where a random `rand_a` only falls 1.6% of the time.
+const SMALL_COUNTER_LIMIT: u16 = 64;
+
+impl RandAMethod {
+    /// The forced method, or a guess (flagged as inferred): a counter when `rand_a` is too small to look random.
+    /// A sub-millisecond fraction looks random in a single ID, so it has to be forced.
+    fn from_args(args: &Args, rand_a: u16) -> (Self, bool) {
+        match args.force {
+            Some(IdFormat::Uuid7SubMs) => (RandAMethod::SubMillisecond, false),
+            Some(IdFormat::Uuid7Counter) => (RandAMethod::Counter, false),
+            _ if rand_a < SMALL_COUNTER_LIMIT => (RandAMethod::Counter, true),
+            _ => (RandAMethod::Random, false),
+        }
+    }
+}
+
+const STANDARD_NAMESPACES: [Uuid; 4] = [Uuid::NAMESPACE_DNS, Uuid::NAMESPACE_URL, Uuid::NAMESPACE_OID, Uuid::NAMESPACE_X500];
+
+fn namespace_label(namespace: &Uuid) -> String {
+    match *namespace {
+        Uuid::NAMESPACE_DNS => "DNS namespace".to_string(),
+        Uuid::NAMESPACE_URL => "URL namespace".to_string(),
+        Uuid::NAMESPACE_OID => "OID namespace".to_string(),
+        Uuid::NAMESPACE_X500 => "X.500 namespace".to_string(),
+        custom => format!("namespace {}", custom),
+    }
+}
+
+/// Without a namespace, hashes the name like Minecraft offline players (Java's `UUID.nameUUIDFromBytes`).
+fn name_based_uuid(version: usize, namespace: Option<&Uuid>, name: &str) -> Option<Uuid> {
+    match (version, namespace) {
+        (3, Some(namespace)) => Some(Uuid::new_v3(namespace, name.as_bytes())),
+        (5, Some(namespace)) => Some(Uuid::new_v5(namespace, name.as_bytes())),
+        (3, None) => Some(Builder::from_md5_bytes(Md5::digest(format!("OfflinePlayer:{}", name)).into()).into_uuid()),
+        _ => None,
+    }
+}
+
+/// Checks a name-based UUID against `--namespace` and `--name`, or searches `--names-from` for its name.
+fn describe_name(args: &Args, uuid: &Uuid) -> Option<String> {
+    let version = uuid.get_version_num();
+    if uuid.get_variant() != Variant::RFC4122 || !(version == 3 || version == 5) {
+        return None;
+    }
+    if let Some(names) = &args.names_from {
+        let namespaces: Vec<Option<&Uuid>> = match &args.namespace {
+            Some(namespace) => vec![Some(namespace)],
+            None => STANDARD_NAMESPACES.iter().map(Some).chain([None]).collect(),
+        };
+        for name in &names.0 {
+            for namespace in &namespaces {
+                if name_based_uuid(version, *namespace, name) == Some(*uuid) {
+                    return Some(match namespace {
+                        Some(namespace) => format!("\"{}\" (name in {})", name, namespace_label(namespace)),
+                        None => format!("\"{}\" (Minecraft offline player)", name),
+                    });
+                }
+            }
+        }
+        return Some(format!("Not found among {} names", names.0.len()));
+    }
+    let (Some(namespace), Some(name)) = (&args.namespace, &args.name) else {
+        return None;
+    };
+    if name_based_uuid(version, Some(namespace), name) == Some(*uuid) {
+        Some(format!("\"{}\" (verified in {})", name, namespace_label(namespace)))
+    } else {
+        Some(format!("Not derived from \"{}\" in {}", name, namespace_label(namespace)))
+    }
+}
 
 pub fn parse_uuid(args: &Args) -> Option<IDInfo> {
     let uuid = Uuid::try_parse(&args.id).ok()?;
@@ -44,7 +160,7 @@ pub fn parse_uuid(args: &Args) -> Option<IDInfo> {
                 };
                 version = match uuid.get_version_num() {
                     1 => Some("1 (timestamp and node)".to_string()),
-                    2 => Some("2 (DCE security)".to_string()),
+                    2 => Some("2 (DCE security, time is a lower bound within ~7 minutes)".to_string()),
                     3 => Some("3 (MD5 hash)".to_string()),
                     4 => Some("4 (random)".to_string()),
                     5 => Some("5 (SHA-1 hash)".to_string()),
@@ -54,12 +170,13 @@ pub fn parse_uuid(args: &Args) -> Option<IDInfo> {
                     ver => Some(format!("{ver} (out of spec)")),
                 };
                 entropy = match uuid.get_version_num() {
-                    1 | 6 => 0,
+                    1 | 2 | 6 => 0,
                     7 => 74,
                     _ => 122,
                 };
                 color_map = match uuid.get_version_num() {
                     1 | 6 => Some(COLOR_MAP_UUID_16.to_string()),
+                    2 => Some(COLOR_MAP_UUID_2.to_string()),
                     7 => Some(COLOR_MAP_UUID_7.to_string()),
                     _ => Some(COLOR_MAP_UUID_GENERIC.to_string()),
                 };
@@ -80,31 +197,69 @@ pub fn parse_uuid(args: &Args) -> Option<IDInfo> {
         let formatted_time = milliseconds_to_seconds_and_iso8601(ms, epoch_ms(args, 0));
         timestamp = Some(formatted_time.0);
         datetime = Some(formatted_time.1);
+    } else if uuid.get_variant() == Variant::RFC4122
+        && let Some(ms) = dce_timestamp_ms(&uuid)
+    {
+        let formatted_time = milliseconds_to_seconds_and_iso8601(ms, epoch_ms(args, 0));
+        timestamp = Some(formatted_time.0);
+        datetime = Some(formatted_time.1);
     }
 
-    let node1: Option<String> = match uuid.get_node_id() {
-        Some(value) => {
-            let mut node1_buff: String = "".to_string();
-            for (i, c) in value.into_iter().enumerate() {
-                node1_buff.push_str(&hex::encode(vec![c]));
-                if i < 5 {
-                    node1_buff.push(':');
-                }
-            }
-            Some(node1_buff)
-        }
-        None => None,
+    let [b0, b1, b2, b3, b4, b5, b6, b7, b8, b9, node @ ..] = *uuid.as_bytes();
+    let is_rfc = uuid.get_variant() == Variant::RFC4122;
+    let rand_a = u16::from_be_bytes([b6 & 0x0f, b7]);
+    let (rand_a_method, inferred) = if is_rfc && uuid.get_version_num() == 7 {
+        RandAMethod::from_args(args, rand_a)
+    } else if matches!(args.force, Some(IdFormat::Uuid7SubMs | IdFormat::Uuid7Counter)) {
+        return None;
+    } else {
+        (RandAMethod::Random, false)
     };
-
-    let sequence: Option<u128> = match uuid.get_version_num() {
-        1 | 6 => {
-            let sequence_bytes = &uuid.as_bytes()[8..10];
-            let mut first_byte = *sequence_bytes.first()?;
-            first_byte <<= 2;
-            first_byte >>= 2;
-            Some(u16::from_be_bytes([first_byte, *sequence_bytes.get(1)?]).into())
+    match rand_a_method {
+        RandAMethod::Random => {}
+        RandAMethod::SubMillisecond => {
+            let unix_ms = u64::from_be_bytes([0, 0, b0, b1, b2, b3, b4, b5]).saturating_add(epoch_ms(args, 0));
+            let (precise_timestamp, precise_datetime) = unix_nanos_to_iso8601(i128::from(unix_ms) * 1_000_000 + i128::from(rand_a) * 1_000_000 / 4096);
+            version = Some("7 (sortable timestamp with sub-millisecond precision)".to_string());
+            entropy = 62;
+            color_map = Some(COLOR_MAP_UUID_7_SUB_MS.to_string());
+            timestamp = Some(precise_timestamp);
+            datetime = Some(precise_datetime);
         }
-        _ => None,
+        RandAMethod::Counter => {
+            version = Some(if inferred {
+                format!("7 (sortable timestamp and counter, inferred {}%)", (4096 - u32::from(SMALL_COUNTER_LIMIT)) * 100 / 4096)
+            } else {
+                "7 (sortable timestamp and counter)".to_string()
+            });
+            entropy = 62;
+            color_map = Some(COLOR_MAP_UUID_7_COUNTER.to_string());
+        }
+    }
+    let (sequence, node1, node2, node3) = match uuid.get_version_num() {
+        1 | 6 if is_rfc => (
+            Some(u16::from_be_bytes([b8 & 0x3f, b9]).into()),
+            Some(format_node(&node)),
+            Some(format!("{:02b} (Variant bits, RFC-4122)", b8 >> 6)),
+            None,
+        ),
+        2 if is_rfc => {
+            let local_id = u32::from_be_bytes([b0, b1, b2, b3]);
+            let (domain, local_id_name) = match b9 {
+                0 => ("Person", "UID"),
+                1 => ("Group", "GID"),
+                2 => ("Organization", "Org ID"),
+                _ => ("Site-defined", "Local ID"),
+            };
+            (
+                Some((b8 & 0x3f).into()),
+                Some(format_node(&node)),
+                Some(format!("{} ({})", local_id, local_id_name)),
+                Some(format!("{} (Local domain {})", domain, b9)),
+            )
+        }
+        7 if rand_a_method == RandAMethod::Counter => (Some(rand_a.into()), None, None, None),
+        _ => (None, describe_name(args, &uuid), None, None),
     };
 
     Some(IDInfo {
@@ -119,6 +274,8 @@ pub fn parse_uuid(args: &Args) -> Option<IDInfo> {
         timestamp,
         sequence,
         node1,
+        node2,
+        node3,
         hex: Some(hex::encode(uuid.as_bytes())),
         bits: Some(uuid.as_bytes().iter().fold(String::new(), |mut output, c| {
             let _ = write!(output, "{c:08b}");
@@ -146,16 +303,24 @@ pub fn parse_short_uuid(args: &Args) -> Option<IDInfo> {
 
 pub fn parse_base64_uuid(args: &Args) -> Option<IDInfo> {
     let mut padded = true;
-    let uuid = match URL_SAFE.decode(&args.id) {
-        Ok(value) => Uuid::from_slice_le(value.as_slice()).ok()?,
+    let value = match URL_SAFE.decode(&args.id) {
+        Ok(value) => value,
         Err(_) => match URL_SAFE_NO_PAD.decode(&args.id) {
             Ok(value) => {
                 padded = false;
-                Uuid::from_slice_le(value.as_slice()).ok()?
+                value
             }
             Err(_) => return None,
         },
     };
+    // .NET encodes `Guid.ToByteArray()` (mixed-endian), but fall back to RFC order when only that yields a valid version:
+    let mut byte_order = "Microsoft byte order";
+    let mut uuid = Uuid::from_slice_le(value.as_slice()).ok()?;
+    let rfc_uuid = Uuid::from_slice(value.as_slice()).ok()?;
+    if !has_valid_version(&uuid) && has_valid_version(&rfc_uuid) {
+        uuid = rfc_uuid;
+        byte_order = "RFC byte order";
+    }
 
     let mut new_args: Args = args.clone();
     new_args.id = uuid.to_string();
@@ -168,7 +333,7 @@ pub fn parse_base64_uuid(args: &Args) -> Option<IDInfo> {
     }
     id_info.standard = args.id.clone();
     id_info.uuid_wrap = Some(uuid.to_string());
-    id_info.parsed = Some("from base64".to_string());
+    id_info.parsed = Some(format!("from base64, {}", byte_order));
     Some(id_info)
 }
 
@@ -198,3 +363,124 @@ pub fn parse_uuid_integer(args: &Args) -> Option<IDInfo> {
     id_info.parsed = Some("as integer".to_string());
     Some(id_info)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::NameList;
+
+    fn parse(id: &str) -> IDInfo {
+        parse_uuid(&Args {
+            id: id.to_string(),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_uuid_v1_node() {
+        let id_info = parse("16689a10-a518-11ef-aa74-4ec6089be97a");
+        assert_eq!(id_info.sequence, Some(10868));
+        assert_eq!(id_info.node1.unwrap(), "4e:c6:08:9b:e9:7a (Locally administered MAC address)");
+        assert_eq!(id_info.node2.unwrap(), "10 (Variant bits, RFC-4122)");
+        assert_eq!(id_info.color_map.unwrap().get(64..66), Some("55"));
+        let id_info = parse("16689a10-a518-11ef-aa74-4fc6089be97a");
+        assert_eq!(id_info.node1.unwrap(), "4f:c6:08:9b:e9:7a (Random node ID, multicast bit set)");
+        let id_info = parse("16689a10-a518-11ef-aa74-005056c00008");
+        assert_eq!(id_info.node1.unwrap(), "00:50:56:c0:00:08 (MAC address, VMware, Inc.)");
+    }
+
+    #[test]
+    fn test_parse_uuid_v2() {
+        let id_info = parse("000003e8-a518-21ef-8a01-4ec6089be97a");
+        assert_eq!(id_info.version.unwrap(), "2 (DCE security, time is a lower bound within ~7 minutes)");
+        assert_eq!(id_info.node2.unwrap(), "1000 (GID)");
+        assert_eq!(id_info.node3.unwrap(), "Group (Local domain 1)");
+        assert_eq!(id_info.sequence, Some(10));
+        assert_eq!(id_info.datetime.unwrap(), "2024-11-17T19:13:00.000Z");
+        assert_eq!(parse("000001f5-a518-21ef-8a00-4ec6089be97a").node2.unwrap(), "501 (UID)");
+    }
+
+    #[test]
+    fn test_parse_uuid_v7_rand_a() {
+        let id = "01941f29-7c00-7aaa-aaaa-aaaaaaaaaaaa";
+        assert_eq!(parse(id).version.unwrap(), "7 (sortable timestamp and random)");
+        let id_info = parse_uuid(&Args {
+            id: id.to_string(),
+            force: Some(IdFormat::Uuid7SubMs),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(id_info.version.unwrap(), "7 (sortable timestamp with sub-millisecond precision)");
+        assert_eq!(id_info.datetime.unwrap(), "2025-01-01T00:00:00.000666503Z");
+        let id_info = parse_uuid(&Args {
+            id: id.to_string(),
+            force: Some(IdFormat::Uuid7Counter),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(id_info.version.unwrap(), "7 (sortable timestamp and counter)");
+        assert_eq!(id_info.sequence, Some(0xaaa));
+        // A rand_a this small is unlikely to be random:
+        let id_info = parse("01941f29-7c00-7001-aaaa-aaaaaaaaaaaa");
+        assert_eq!(id_info.version.unwrap(), "7 (sortable timestamp and counter, inferred 98%)");
+        assert_eq!(id_info.sequence, Some(1));
+        assert_eq!(parse("01941f29-7c00-7040-aaaa-aaaaaaaaaaaa").version.unwrap(), "7 (sortable timestamp and random)");
+        assert!(
+            parse_uuid(&Args {
+                id: "8584c629-371f-4fc6-a1af-ae5201e8f210".to_string(),
+                force: Some(IdFormat::Uuid7Counter),
+                ..Default::default()
+            })
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_parse_base64_uuid_byte_order() {
+        let args = Args {
+            id: "UHKjBazX_UG8dEAJaikK1g==".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(parse_base64_uuid(&args).unwrap().parsed.unwrap(), "from base64, Microsoft byte order");
+        let args = Args {
+            id: "hYTGKTcfT8ahr65SAejyEA==".to_string(),
+            ..Default::default()
+        };
+        let id_info = parse_base64_uuid(&args).unwrap();
+        assert_eq!(id_info.parsed.unwrap(), "from base64, RFC byte order");
+        assert_eq!(id_info.uuid_wrap.unwrap(), "8584c629-371f-4fc6-a1af-ae5201e8f210");
+    }
+
+    #[test]
+    fn test_parse_uuid_name_verification() {
+        let args = Args {
+            id: "cfbff0d1-9375-5685-968c-48ce8b15ae17".to_string(),
+            namespace: Some(Uuid::NAMESPACE_DNS),
+            name: Some("example.com".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(parse_uuid(&args).unwrap().node1.unwrap(), "\"example.com\" (verified in DNS namespace)");
+        let args = Args {
+            name: Some("example.org".to_string()),
+            ..args
+        };
+        assert_eq!(parse_uuid(&args).unwrap().node1.unwrap(), "Not derived from \"example.org\" in DNS namespace");
+        assert!(parse("cfbff0d1-9375-5685-968c-48ce8b15ae17").node1.is_none());
+    }
+
+    #[test]
+    fn test_parse_uuid_names_from() {
+        let names = Some(NameList(vec!["jeb_".to_string(), "https://example.com/".to_string(), "Notch".to_string()]));
+        let mut args = Args {
+            id: "b9dcdff8-af4a-365d-8043-0f8361942709".to_string(),
+            names_from: names.clone(),
+            ..Default::default()
+        };
+        assert_eq!(parse_uuid(&args).unwrap().node1.unwrap(), "\"https://example.com/\" (name in URL namespace)");
+        args.id = "b50ad385-829d-3141-a216-7e7d7539ba7f".to_string();
+        assert_eq!(parse_uuid(&args).unwrap().node1.unwrap(), "\"Notch\" (Minecraft offline player)");
+        args.id = "cfbff0d1-9375-5685-968c-48ce8b15ae17".to_string();
+        assert_eq!(parse_uuid(&args).unwrap().node1.unwrap(), "Not found among 3 names");
+    }
+}
@@ -1,33 +1,44 @@
+use chrono::{DateTime, Utc};
+
 use crate::schema::{Args, IDInfo, IdFormat};
 
 use crate::formats::asin::parse_asin;
+use crate::formats::bank::{parse_aba, parse_bic, parse_sort_code};
 use crate::formats::bitcoin::parse_bitcoin;
 use crate::formats::breezeid::parse_breezeid;
+use crate::formats::card::{is_masked_pan, parse_pan};
 use crate::formats::commerce::parse_commerce;
 use crate::formats::cuid::{parse_cuid1, parse_cuid2};
+use crate::formats::database::parse_database_uuid;
 use crate::formats::datadog::parse_datadog;
 use crate::formats::duns::parse_duns;
+use crate::formats::epochs::{parse_cocoa, parse_dotnet_ticks, parse_excel, parse_filetime, parse_gps, parse_hfs, parse_julian_day, parse_ldap, parse_ntp, parse_webkit};
 use crate::formats::ethereum::parse_ethereum;
 use crate::formats::flake::parse_flake;
 use crate::formats::gdocs::parse_gdocs;
 use crate::formats::geo::parse_h3;
+use crate::formats::guid::{parse_comb, parse_guid_bytes, parse_newsequentialid};
 use crate::formats::hash::parse_hash;
 use crate::formats::hashid::parse_hashid;
 use crate::formats::iban::parse_iban;
 use crate::formats::ipfs::parse_ipfs;
 use crate::formats::isbn::{parse_isbn, parse_isbn10};
 use crate::formats::ksuid::parse_ksuid;
+use crate::formats::misskey::{parse_aid, parse_aidx, parse_meid, parse_meidg, parse_misskey_objectid};
+use crate::formats::mobile::{parse_esn, parse_iccid, parse_imei, parse_imeisv, parse_imsi, parse_mobile_meid};
 use crate::formats::nano64::parse_nano64;
 use crate::formats::nanoid::parse_nanoid;
-use crate::formats::network::{parse_imei, parse_ipv4, parse_ipv6, parse_mac};
+use crate::formats::network::{parse_ipv4, parse_ipv6, parse_mac};
 use crate::formats::nuid::parse_nuid;
 use crate::formats::objectid::parse_objectid;
 use crate::formats::orderlyid::parse_orderlyid;
+use crate::formats::publishing::{parse_doi, parse_ismn, parse_isni, parse_isrc, parse_issn, parse_iswc};
 use crate::formats::puid::{parse_puid, parse_puid_any, parse_shortpuid};
 use crate::formats::pushid::parse_pushid;
 use crate::formats::scru::{parse_scru64, parse_scru128};
+use crate::formats::securities::{parse_cusip, parse_figi, parse_isin, parse_lei, parse_sedol};
 use crate::formats::slack::parse_slack;
-use crate::formats::snowflake::parse_snowflake;
+use crate::formats::snowflake::{SNOWFLAKE_VARIANTS, parse_snowflake};
 use crate::formats::snowid::parse_snowid;
 use crate::formats::spotify::parse_spotify;
 use crate::formats::sqid::parse_sqid;
@@ -55,6 +66,9 @@ pub static ALL_PARSERS: &[ParseFunction] = &[
     parse_uuid25,
     parse_short_uuid,
     parse_uuid_integer,
+    parse_newsequentialid,
+    parse_guid_bytes,
+    parse_database_uuid,
     parse_ulid,
     parse_julid,
     parse_upid,
@@ -79,7 +93,22 @@ pub static ALL_PARSERS: &[ParseFunction] = &[
     parse_stripe,
     parse_datadog,
     parse_snowflake,
+    parse_aid,
+    parse_aidx,
+    parse_meid,
+    parse_meidg,
+    parse_misskey_objectid,
     parse_unix,
+    parse_filetime,
+    parse_dotnet_ticks,
+    parse_cocoa,
+    parse_webkit,
+    parse_ntp,
+    parse_gps,
+    parse_excel,
+    parse_julian_day,
+    parse_ldap,
+    parse_hfs,
     parse_hash,
     parse_ipfs,
     parse_breezeid,
@@ -97,11 +126,31 @@ pub static ALL_PARSERS: &[ParseFunction] = &[
     parse_cuid2,
     parse_h3,
     parse_imei,
+    parse_imeisv,
+    parse_mobile_meid,
+    parse_esn,
+    parse_iccid,
+    parse_imsi,
+    parse_pan,
     parse_gdocs,
     parse_slack,
     parse_spotify,
     parse_swhid,
     parse_iban,
+    parse_isin,
+    parse_cusip,
+    parse_sedol,
+    parse_figi,
+    parse_lei,
+    parse_bic,
+    parse_aba,
+    parse_sort_code,
+    parse_issn,
+    parse_ismn,
+    parse_isrc,
+    parse_iswc,
+    parse_isni,
+    parse_doi,
     parse_bitcoin,
     parse_ethereum,
     parse_commerce,
@@ -117,6 +166,10 @@ pub fn parse_all(args: &Args) -> Vec<IDInfo> {
             valid_ids.push(value);
         }
     }
+    if is_masked_pan(args) {
+        // Other interpretations would show the digits of the card number:
+        valid_ids.retain(|value| value.id_type == "Payment Card Number");
+    }
     valid_ids
 }
 
@@ -129,14 +182,107 @@ pub fn pick_first_valid(args: &Args, parsers: &[ParseFunction]) -> Option<IDInfo
     None
 }
 
+/// Every interpretation of the ID that has a valid date: all the parsers, plus each Snowflake variant.
+pub fn timed_interpretations(args: &Args) -> Vec<IDInfo> {
+    if is_masked_pan(args) {
+        // A card number has no date, and each other reading would give its digits away:
+        return vec![];
+    }
+    let mut interpretations: Vec<IDInfo> = ALL_PARSERS.iter().filter_map(|parser| parser(args)).collect();
+    if args.id.trim().parse::<u64>().is_ok() {
+        // Each Snowflake variant is listed on its own instead of only the inferred one:
+        interpretations.retain(|value| value.id_type != "Snowflake");
+        for variant in SNOWFLAKE_VARIANTS {
+            let variant_args = Args {
+                force: Some(variant.id_format),
+                ..args.clone()
+            };
+            interpretations.extend(parse_snowflake(&variant_args));
+        }
+    }
+    interpretations.retain(|value| value.datetime.as_deref().is_some_and(|datetime| datetime != "Invalid"));
+    interpretations
+}
+
+pub fn timestamp_seconds(value: &IDInfo) -> f64 {
+    value.timestamp.clone().unwrap_or_default().parse::<f64>().unwrap_or_default()
+}
+
+/// Window of Unix seconds from `--near` (a single instant) or `--between`.
+fn target_window(args: &Args) -> Option<(f64, f64)> {
+    let seconds = |datetime: &DateTime<Utc>| datetime.timestamp_millis() as f64 / 1_000.0;
+    match (&args.near, args.between.as_deref()) {
+        (Some(near), _) => Some((seconds(near), seconds(near))),
+        (None, Some([start, end])) => Some((seconds(start).min(seconds(end)), seconds(start).max(seconds(end)))),
+        _ => None,
+    }
+}
+
+/// Index of the interpretation closest to the `--near`/`--between` window, preferring the
+/// center of the window when several fall inside it.
+pub fn best_match(args: &Args, interpretations: &[IDInfo]) -> Option<usize> {
+    let (start, end) = target_window(args)?;
+    let middle = (start + end) / 2.0;
+    interpretations
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let timestamp = timestamp_seconds(value);
+            let distance = (start - timestamp).max(timestamp - end).max(0.0);
+            (index, distance, (timestamp - middle).abs())
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1).then(a.2.total_cmp(&b.2)))
+        .map(|(index, _, _)| index)
+}
+
 pub fn auto_detect(args: &Args) -> Option<IDInfo> {
+    let id_info = detect_by_shape(args);
+    if (args.near.is_some() || args.between.is_some()) && !is_masked_pan(args) {
+        // Among equally confident interpretations, pick the one whose date matches what the user expects:
+        let mut interpretations = timed_interpretations(args);
+        if id_info.as_ref().is_some_and(|value| value.high_confidence) {
+            interpretations.retain(|value| value.high_confidence);
+        }
+        if let Some(index) = best_match(args, &interpretations) {
+            return interpretations.into_iter().nth(index);
+        }
+    }
+    id_info
+}
+
+fn detect_by_shape(args: &Args) -> Option<IDInfo> {
     let mut id_info: Option<IDInfo>;
     if let Some(result) = parse_iban(args) {
         return Some(result);
     }
+    // Another interpretation would print the digits of a card number that is shown masked:
+    if is_masked_pan(args) {
+        return parse_pan(args);
+    }
     if args.id.trim().parse::<u128>().is_ok() {
         // Numeric:
-        id_info = pick_first_valid(args, &[parse_isbn, parse_imei, parse_unix_recent, parse_snowflake, parse_uuid_integer]);
+        id_info = pick_first_valid(
+            args,
+            &[
+                parse_ismn,
+                parse_isbn,
+                parse_imei,
+                parse_iccid,
+                parse_imsi,
+                parse_pan,
+                parse_unix_recent,
+                parse_snowflake,
+                parse_uuid_integer,
+            ],
+        );
+    } else if args
+        .id
+        .trim()
+        .strip_prefix('-')
+        .is_some_and(|unsigned| unsigned.chars().all(|c| c.is_ascii_digit() || c == '.') && unsigned.parse::<f64>().is_ok())
+    {
+        // Negative number, rather than base64 starting with "-":
+        id_info = parse_unix(args);
     } else {
         // Fixed length:
         id_info = match args.id.chars().count() {
@@ -146,23 +292,25 @@ pub fn auto_detect(args: &Args) -> Option<IDInfo> {
             42 => pick_first_valid(args, &[parse_ethereum, parse_bitcoin]),
             40 => parse_ksuid(args),
             34 => parse_bitcoin(args),
-            32 | 36 => pick_first_valid(args, &[parse_datadog, parse_uuid]),
+            32 | 36 => pick_first_valid(args, &[parse_datadog, parse_newsequentialid, parse_database_uuid, parse_guid_bytes, parse_uuid]),
             27 => pick_first_valid(args, &[parse_upid, parse_ksuid]),
             26 => parse_ulid_any(args),
             25 => pick_first_valid(args, &[parse_cuid1, parse_scru128]),
-            24 => pick_first_valid(args, &[parse_objectid, parse_puid, parse_base64_uuid]),
+            24 => pick_first_valid(args, &[parse_meidg, parse_meid, parse_objectid, parse_puid, parse_base64_uuid]),
             22 => pick_first_valid(args, &[parse_short_uuid, parse_timeflake_base62, parse_base64_uuid, parse_nuid, parse_spotify]),
             21 => parse_nanoid(args),
-            20 => pick_first_valid(args, &[parse_xid, parse_stripe, parse_pushid]),
+            20 => pick_first_valid(args, &[parse_lei, parse_xid, parse_stripe, parse_pushid]),
             18 => parse_flake(args),
             17 => pick_first_valid(args, &[parse_vin, parse_nano64]),
-            16 => parse_nano64(args),
-            15 => parse_h3(args),
-            14 => parse_shortpuid(args),
+            16 => pick_first_valid(args, &[parse_nano64, parse_aidx]),
+            15 => pick_first_valid(args, &[parse_h3, parse_mobile_meid]),
+            14 => pick_first_valid(args, &[parse_mobile_meid, parse_shortpuid]),
             13 => pick_first_valid(args, &[parse_tid, parse_tsid]),
-            12 => pick_first_valid(args, &[parse_scru64, parse_shortpuid]),
-            11 => pick_first_valid(args, &[parse_slack, parse_youtube, parse_snowid]),
-            10 => pick_first_valid(args, &[parse_asin, parse_snowid]),
+            12 => pick_first_valid(args, &[parse_figi, parse_isin, parse_isrc, parse_scru64, parse_shortpuid]),
+            11 => pick_first_valid(args, &[parse_iswc, parse_bic, parse_slack, parse_youtube, parse_snowid]),
+            10 => pick_first_valid(args, &[parse_asin, parse_ipv4, parse_snowid, parse_aid]),
+            9 => pick_first_valid(args, &[parse_issn, parse_cusip]),
+            8 => pick_first_valid(args, &[parse_bic, parse_sort_code]),
             _ => None,
         };
         // Variable length:
@@ -170,14 +318,22 @@ pub fn auto_detect(args: &Args) -> Option<IDInfo> {
             Some(value) => Some(value),
             #[rustfmt::skip]
             None => pick_first_valid(args, &[
+                parse_database_uuid,
                 parse_vin,
                 parse_orderlyid,
+                parse_ismn,
                 parse_isbn,
+                parse_doi,
+                parse_isni,
+                parse_isrc,
+                parse_iswc,
                 parse_commerce,
                 parse_typeid,
                 parse_ipfs,
                 parse_stripe,
                 parse_breezeid,
+                parse_ldap,
+                parse_unix_recent,
                 parse_ipv4,
                 parse_ipv6,
                 parse_mac,
@@ -187,6 +343,8 @@ pub fn auto_detect(args: &Args) -> Option<IDInfo> {
                 parse_duns,
                 parse_threads,
                 parse_imei,
+                parse_imeisv,
+                parse_pan,
                 parse_hashid,
                 parse_nanoid,
                 parse_swhid,
@@ -197,12 +355,20 @@ pub fn auto_detect(args: &Args) -> Option<IDInfo> {
 }
 
 pub fn force_format(args: &Args) -> Option<IDInfo> {
+    if args.force != Some(IdFormat::Pan) && is_masked_pan(args) {
+        // Other formats would show the digits of a card number that is shown masked:
+        return None;
+    }
     match &args.force? {
-        IdFormat::Uuid => parse_uuid(args),
+        IdFormat::Uuid | IdFormat::Uuid7SubMs | IdFormat::Uuid7Counter => parse_uuid(args),
         IdFormat::Shortuuid => parse_short_uuid(args),
         IdFormat::UuidB64 => parse_base64_uuid(args),
         IdFormat::Uuid25 => parse_uuid25(args),
         IdFormat::UuidInt => parse_uuid_integer(args),
+        IdFormat::GuidBytes => parse_guid_bytes(args),
+        IdFormat::Newsequentialid => parse_newsequentialid(args),
+        IdFormat::Comb => parse_comb(args),
+        IdFormat::DatabaseUuid => parse_database_uuid(args),
         IdFormat::Ulid => parse_ulid(args),
         IdFormat::Julid => parse_julid(args),
         IdFormat::Upid => parse_upid(args),
@@ -226,6 +392,16 @@ pub fn force_format(args: &Args) -> Option<IDInfo> {
         IdFormat::SfFrostflake => parse_snowflake(args),
         IdFormat::SfFlakeid => parse_snowflake(args),
         IdFormat::SfSimpleflake => parse_snowflake(args),
+        IdFormat::SfSonyV2 => parse_snowflake(args),
+        IdFormat::SfBaidu => parse_snowflake(args),
+        IdFormat::SfLeaf => parse_snowflake(args),
+        IdFormat::SfYitter => parse_snowflake(args),
+        IdFormat::SfTiktok => parse_snowflake(args),
+        IdFormat::MisskeyAid => parse_aid(args),
+        IdFormat::MisskeyAidx => parse_aidx(args),
+        IdFormat::MisskeyMeid => parse_meid(args),
+        IdFormat::MisskeyMeidg => parse_meidg(args),
+        IdFormat::MisskeyObjectid => parse_misskey_objectid(args),
         IdFormat::Tsid => parse_tsid(args),
         IdFormat::Sqid => parse_sqid(args),
         IdFormat::Hashid => parse_hashid(args),
@@ -236,6 +412,16 @@ pub fn force_format(args: &Args) -> Option<IDInfo> {
         IdFormat::UnixMs => parse_unix_ms(args),
         IdFormat::UnixUs => parse_unix_us(args),
         IdFormat::UnixNs => parse_unix_ns(args),
+        IdFormat::Filetime => parse_filetime(args),
+        IdFormat::DotnetTicks => parse_dotnet_ticks(args),
+        IdFormat::Cocoa => parse_cocoa(args),
+        IdFormat::Webkit => parse_webkit(args),
+        IdFormat::Ntp => parse_ntp(args),
+        IdFormat::Gps => parse_gps(args),
+        IdFormat::Excel => parse_excel(args),
+        IdFormat::JulianDay => parse_julian_day(args),
+        IdFormat::Ldap => parse_ldap(args),
+        IdFormat::Hfs => parse_hfs(args),
         IdFormat::Datadog => parse_datadog(args),
         IdFormat::Hash => parse_hash(args),
         IdFormat::Ipfs => parse_ipfs(args),
@@ -248,6 +434,12 @@ pub fn force_format(args: &Args) -> Option<IDInfo> {
         IdFormat::Ipv6 => parse_ipv6(args),
         IdFormat::Mac => parse_mac(args),
         IdFormat::Imei => parse_imei(args),
+        IdFormat::Imeisv => parse_imeisv(args),
+        IdFormat::Meid => parse_mobile_meid(args),
+        IdFormat::Esn => parse_esn(args),
+        IdFormat::Iccid => parse_iccid(args),
+        IdFormat::Imsi => parse_imsi(args),
+        IdFormat::Pan => parse_pan(args),
         IdFormat::Isbn => parse_isbn(args),
         IdFormat::Tid => parse_tid(args),
         IdFormat::Threads => parse_threads(args),
@@ -262,6 +454,20 @@ pub fn force_format(args: &Args) -> Option<IDInfo> {
         IdFormat::Orderlyid => parse_orderlyid(args),
         IdFormat::Swhid => parse_swhid(args),
         IdFormat::Iban => parse_iban(args),
+        IdFormat::Isin => parse_isin(args),
+        IdFormat::Cusip => parse_cusip(args),
+        IdFormat::Sedol => parse_sedol(args),
+        IdFormat::Figi => parse_figi(args),
+        IdFormat::Lei => parse_lei(args),
+        IdFormat::Bic => parse_bic(args),
+        IdFormat::Aba => parse_aba(args),
+        IdFormat::SortCode => parse_sort_code(args),
+        IdFormat::Issn => parse_issn(args),
+        IdFormat::Ismn => parse_ismn(args),
+        IdFormat::Isrc => parse_isrc(args),
+        IdFormat::Iswc => parse_iswc(args),
+        IdFormat::Isni => parse_isni(args),
+        IdFormat::Doi => parse_doi(args),
         IdFormat::Bitcoin => parse_bitcoin(args),
         IdFormat::Ethereum => parse_ethereum(args),
         IdFormat::Commerce => parse_commerce(args),
@@ -1,4 +1,5 @@
 use super::*;
+use crate::schema::parse_date;
 
 #[test]
 fn test_auto_detect() {
@@ -14,7 +15,7 @@ fn test_auto_detect() {
 
     // UUID:
     _assert("16689a10-a518-11ef-aa74-4ec6089be97a", "UUID (RFC-4122)", "1 (timestamp and node)");
-    _assert("215d3d9f-e980-2cf4-9191-7dd485ba4fee", "UUID (RFC-4122)", "2 (DCE security)");
+    _assert("215d3d9f-e980-2cf4-9191-7dd485ba4fee", "UUID (RFC-4122)", "2 (DCE security, time is a lower bound within ~7 minutes)");
     _assert("6fc22fc2-8e36-3ab9-888f-d6fbd3af370a", "UUID (RFC-4122)", "3 (MD5 hash)");
     _assert("8584c629-371f-4fc6-a1af-ae5201e8f210", "UUID (RFC-4122)", "4 (random)");
     _assert("26ad69ad-4c50-5737-bb66-bd46328ecf8a", "UUID (RFC-4122)", "5 (SHA-1 hash)");
@@ -30,13 +31,28 @@ fn test_auto_detect() {
     _assert("UHKjBazX_UG8dEAJaikK1g==", "Padded Base64 of UUID (RFC-4122)", "4 (random)");
     _assert("UHKjBazX_UG8dEAJaikK1g", "Unpadded Base64 of UUID (RFC-4122)", "4 (random)");
     _assert("2093703425379131962944436515747969848", "Integer of UUID (RFC-9562)", "7 (sortable timestamp and random)");
-    // Snowflakes:
-    _assert("1400000000000000000", "Snowflake", "Unknown (use -f to specify version)");
+    _assert("29c684851f37c64fa1afae5201e8f210", "Microsoft byte order of UUID (RFC-4122)", "4 (random)");
+    _assert("109a6816-18a5-ef11-aa74-4ec6089be97a", "SQL Server NEWSEQUENTIALID", "1 (timestamp and node)");
+    _assert("\\x8584c629371f4fc6a1afae5201e8f210", "PostgreSQL bytea of UUID (RFC-4122)", "4 (random)");
+    _assert("HEXTORAW('8584C629371F4FC6A1AFAE5201E8F210')", "Oracle RAW of UUID (RFC-4122)", "4 (random)");
+    _assert("0x11EFA51816689A10AA744EC6089BE97A", "SQL hex literal of UUID (RFC-4122)", "1 (timestamp and node)");
+    _assert("11efa51816689a10aa744ec6089be97a", "Hex of UUID (RFC-4122)", "1 (timestamp and node)");
+    // Snowflakes (the inferred variant depends on the ID's age, so the clock is pinned to 2026-05-16T19:05:33Z):
+    let snowflake = auto_detect(&Args {
+        id: "1400000000000000000".to_string(),
+        now: chrono::DateTime::from_timestamp(1778958333, 0),
+        ..Default::default()
+    })
+    .unwrap();
+    assert_eq!(snowflake.id_type, "Snowflake");
+    assert_eq!(snowflake.version.unwrap(), "Discord or Spaceflake (inferred 23% each, runner-up: Twitter 21%)");
     // Unix timestamp
     _assert("1734971723", "Unix timestamp", "Assuming seconds");
     _assert("1734971723000", "Unix timestamp", "Assuming milliseconds");
     _assert("1734971723000000", "Unix timestamp", "Assuming microseconds");
     _assert("1734971723000000000", "Unix timestamp", "Assuming nanoseconds");
+    _assert("-86400", "Unix timestamp", "Assuming seconds");
+    _assert("-0.5", "Unix timestamp", "Assuming seconds");
     // Other:
     _assert("01JCXSGZMZQQJ2M93WC0T8KT02", "ULID", "-");
     _assert("01K3ESSGBY0002QCB9YXT6Q6MN", "Julid", "-");
@@ -96,11 +112,47 @@ fn test_auto_detect() {
     _assert("127.0.0.1", "IPv4 Address", "Loopback");
     _assert("10.0.0.1", "IPv4 Address", "Private");
     _assert("200.0.0.1", "IPv4 Address", "-");
+    _assert("100.64.0.0/10", "IPv4 Network (CIDR)", "Shared address space (CGNAT)");
+    _assert("[::1]:443", "IPv6 Socket Address", "Loopback");
+    _assert("2001:db8::/32", "IPv6 Network (CIDR)", "Documentation");
     _assert("::1", "IPv6 Address", "Loopback");
     _assert("1::1", "IPv6 Address", "-");
     _assert("00:00:00:00:00:00", "MAC Address", "-");
     _assert("35-588906-014977-7", "IMEI", "-");
     _assert("355889060149777", "IMEI", "-");
+    _assert("35-588906-014977-23", "IMEISV", "-");
+    _assert("A0000000002329", "MEID", "-");
+    _assert("A00000000023299", "MEID", "-");
+    _assert("8944100000000000000", "ICCID", "-");
+    _assert("310260123456789", "IMSI", "-");
+    _assert("4111 1111 1111 1111", "Payment Card Number", "Visa");
+    _assert("5555555555554444", "Payment Card Number", "Mastercard");
+    _assert("378282246310005", "Payment Card Number", "American Express");
+    // Securities:
+    _assert("US0378331005", "ISIN", "US (United States)");
+    _assert("VN000000VNM8", "ISIN", "VN (Vietnam)");
+    _assert("NGMTNN000002", "ISIN", "NG (Nigeria)");
+    _assert("38259P508", "CUSIP", "North America");
+    _assert("BBG000BLNNH6", "FIGI", "-");
+    _assert("5493001KJTIIGC8Y1R12", "LEI", "-");
+    // Banking:
+    _assert("DEUTDEFF", "BIC (SWIFT code)", "Production BIC");
+    _assert("NWBKGB2L100", "BIC (SWIFT code)", "Production BIC");
+    _assert("VCBVVNVX", "BIC (SWIFT code)", "Production BIC");
+    _assert("20-00-00", "UK Sort Code", "-");
+    // Publishing:
+    _assert("0378-5955", "ISSN", "-");
+    _assert("979-0-2600-0043-8", "ISMN", "-");
+    _assert("9790260000438", "ISMN", "-");
+    _assert("M-2306-7118-7", "ISMN", "-");
+    _assert("USRC17607839", "ISRC", "US (United States)");
+    _assert("US-RC1-76-07839", "ISRC", "US (United States)");
+    _assert("T-034.524.680-1", "ISWC", "-");
+    _assert("T0345246801", "ISWC", "-");
+    _assert("0000-0002-1825-0097", "ISNI", "ORCID iD");
+    _assert("0000 0001 2146 438X", "ISNI", "ISNI");
+    _assert("10.1000/182", "DOI", "-");
+    _assert("https://doi.org/10.1038/nphys1170", "DOI", "-");
     // ISBN:
     _assert("978-0-553-38257-0", "ISBN-13", "-");
     _assert("9780553382570", "ISBN-13", "-");
@@ -119,6 +171,56 @@ fn test_auto_detect() {
     _assert("1-06-14141-000415", "Commerce Barcode", "GTIN-14, grouping/packaging level");
     // VIN:
     _assert("1HGCM82633A004352", "VIN (Vehicle Identification Number)", "Honda, United States");
+    // Misskey:
+    _assert("9jzd4e390a1b0001", "Misskey ID", "aidx");
+    _assert("818ac142d5550123456789ab", "Misskey ID", "meid");
+    _assert("g18bcfe568000123456789ab", "Misskey ID", "meidg");
+    // LDAP timestamp:
+    _assert("20240101000000.0Z", "LDAP timestamp", "Generalized Time");
+}
+
+#[test]
+fn test_auto_detect_near() {
+    fn _assert(id: &str, near: Option<&str>, between: Option<[&str; 2]>, id_type: &str, version: &str) {
+        let id_info = auto_detect(&Args {
+            id: id.to_string(),
+            near: near.map(|value| parse_date(value).unwrap()),
+            between: between.map(|values| values.iter().map(|value| parse_date(value).unwrap()).collect()),
+            ..Default::default()
+        })
+        .expect(id_type);
+        assert_eq!(id_info.id_type, id_type.to_string(), "{id} - {id_type} - {version}");
+        assert_eq!(id_info.version.unwrap_or("-".to_string()), version.to_string(), "{id} - {id_type} - {version}");
+    }
+
+    _assert("1000000000000000000", Some("2018-01-01"), None, "Snowflake", "Twitter");
+    _assert("1000000000000000000", Some("2022-07-01"), None, "Snowflake", "Discord");
+    _assert("1000000000000000000", Some("2001-09-01"), None, "Unix timestamp", "Assuming nanoseconds");
+    _assert("1000000000000000000", None, Some(["2034-01-01", "2032-01-01"]), "Snowflake", "Sony");
+    // A low-confidence interpretation (Cocoa timestamp) closer to the date does not win:
+    _assert("1734971723", Some("2055-12-01"), None, "Unix timestamp", "Assuming seconds");
+}
+
+#[test]
+fn test_auto_detect_not() {
+    fn _assert(id: &str, id_type: &str) {
+        let id_info = auto_detect(&Args {
+            id: id.to_string(),
+            ..Default::default()
+        });
+        assert_ne!(id_info.map(|value| value.id_type), Some(id_type.to_string()), "{id} - {id_type}");
+    }
+
+    _assert("-86400", "Thread ID (Meta Threads)");
+    _assert("9jzd4e39aw", "Misskey ID");
+    // Dates, versions and hex numbers are valid inet_aton() notations, only taken with -f ipv4:
+    _assert("19.10.2023", "IPv4 Address");
+    _assert("01.02.2024", "IPv4 Address");
+    _assert("0x6553f100", "IPv4 Address");
+    _assert("127.1", "IPv4 Address");
+    // Uppercase hex numbers fit the BIC pattern, only taken with -f bic:
+    _assert("DEADBEEF", "BIC (SWIFT code)");
+    _assert("CAFEBABE", "BIC (SWIFT code)");
 }
 
 #[test]
@@ -136,13 +238,25 @@ fn test_force_format() {
 
     // UUID:
     _assert("16689a10-a518-11ef-aa74-4ec6089be97a", IdFormat::Uuid, "UUID (RFC-4122)", "1 (timestamp and node)");
-    _assert("215d3d9f-e980-2cf4-9191-7dd485ba4fee", IdFormat::Uuid, "UUID (RFC-4122)", "2 (DCE security)");
+    _assert(
+        "215d3d9f-e980-2cf4-9191-7dd485ba4fee",
+        IdFormat::Uuid,
+        "UUID (RFC-4122)",
+        "2 (DCE security, time is a lower bound within ~7 minutes)",
+    );
     _assert("6fc22fc2-8e36-3ab9-888f-d6fbd3af370a", IdFormat::Uuid, "UUID (RFC-4122)", "3 (MD5 hash)");
     _assert("8584c629-371f-4fc6-a1af-ae5201e8f210", IdFormat::Uuid, "UUID (RFC-4122)", "4 (random)");
     _assert("26ad69ad-4c50-5737-bb66-bd46328ecf8a", IdFormat::Uuid, "UUID (RFC-4122)", "5 (SHA-1 hash)");
     _assert("1efa519c-2b25-6fd0-8fa1-610b58ceebbe", IdFormat::Uuid, "UUID (RFC-9562)", "6 (sortable timestamp and node)");
     _assert("01933b8c-7875-7b8e-b5fa-bb500eb8bb38", IdFormat::Uuid, "UUID (RFC-9562)", "7 (sortable timestamp and random)");
     _assert("4a0b86fe-afe0-86b9-8b1e-3375b2be3580", IdFormat::Uuid, "UUID (RFC-9562)", "8 (custom)");
+    _assert(
+        "01933b8c-7875-7b8e-b5fa-bb500eb8bb38",
+        IdFormat::Uuid7SubMs,
+        "UUID (RFC-9562)",
+        "7 (sortable timestamp with sub-millisecond precision)",
+    );
+    _assert("01933b8c-7875-7b8e-b5fa-bb500eb8bb38", IdFormat::Uuid7Counter, "UUID (RFC-9562)", "7 (sortable timestamp and counter)");
     _assert("00000000-0000-0000-0000-000000000000", IdFormat::Uuid, "Nil UUID (all zeros)", "-");
     _assert("ffffffff-ffff-ffff-ffff-ffffffffffff", IdFormat::Uuid, "Max UUID (all ones)", "-");
     _assert("906b4e7f-84a3-a0ed-1191-2dea8b497113", IdFormat::Uuid, "NCS UUID", "-");
@@ -158,6 +272,15 @@ fn test_force_format() {
     _assert("UHKjBazX_UG8dEAJaikK1g==", IdFormat::UuidB64, "Padded Base64 of UUID (RFC-4122)", "4 (random)");
     _assert("UHKjBazX_UG8dEAJaikK1g", IdFormat::UuidB64, "Unpadded Base64 of UUID (RFC-4122)", "4 (random)");
     _assert("dpoadk8izg9y4tte7vy1xt94o", IdFormat::Uuid25, "Uuid25 of UUID (RFC-4122)", "4 (random)");
+    _assert("29c684851f37c64fa1afae5201e8f210", IdFormat::GuidBytes, "Microsoft byte order of UUID (RFC-4122)", "4 (random)");
+    _assert(
+        "109a6816-18a5-ef11-aa74-4ec6089be97a",
+        IdFormat::Newsequentialid,
+        "SQL Server NEWSEQUENTIALID",
+        "1 (timestamp and node)",
+    );
+    _assert("8584c629-371f-4fc6-a1af-b22b00cf5a2e", IdFormat::Comb, "COMB of UUID (RFC-4122)", "4 (random)");
+    _assert("8584c629371f4fc6a1afae5201e8f210", IdFormat::DatabaseUuid, "Hex of UUID (RFC-4122)", "4 (random)");
     _assert(
         "2093703425379131962944436515747969848",
         IdFormat::UuidInt,
@@ -176,6 +299,17 @@ fn test_force_format() {
     _assert("7423342004626526207", IdFormat::SfFrostflake, "Snowflake", "Frostflake");
     _assert("JERHwh5PXjL", IdFormat::SfFrostflake, "Snowflake", "Frostflake");
     _assert("3594162604452825250", IdFormat::SfSimpleflake, "Snowflake", "Simpleflake");
+    _assert("24008867184967722", IdFormat::SfSonyV2, "Snowflake", "Sony v2 (10 ms time unit)");
+    _assert("8120113273461424135", IdFormat::SfBaidu, "Snowflake", "Baidu UidGenerator");
+    _assert("1724551110456258569", IdFormat::SfLeaf, "Snowflake", "Meituan Leaf");
+    _assert("482651332526149", IdFormat::SfYitter, "Snowflake", "Yitter");
+    _assert("7301444403323456789", IdFormat::SfTiktok, "Snowflake", "TikTok");
+    // Misskey:
+    _assert("9jzd4e39aw", IdFormat::MisskeyAid, "Misskey ID", "aid");
+    _assert("9jzd4e390a1b0001", IdFormat::MisskeyAidx, "Misskey ID", "aidx");
+    _assert("818ac142d5550123456789ab", IdFormat::MisskeyMeid, "Misskey ID", "meid");
+    _assert("g18bcfe568000123456789ab", IdFormat::MisskeyMeidg, "Misskey ID", "meidg");
+    _assert("6553f1000123456789abcdef", IdFormat::MisskeyObjectid, "Misskey ID", "objectid");
     // Unix timestamp
     _assert("1734971723", IdFormat::Unix, "Unix timestamp", "Assuming seconds");
     _assert("1734971723000", IdFormat::Unix, "Unix timestamp", "Assuming milliseconds");
@@ -185,6 +319,18 @@ fn test_force_format() {
     _assert("1734971723000", IdFormat::UnixMs, "Unix timestamp", "As milliseconds");
     _assert("1734971723000000", IdFormat::UnixUs, "Unix timestamp", "As microseconds");
     _assert("1734971723000000000", IdFormat::UnixNs, "Unix timestamp", "As nanoseconds");
+    // Other epoch timestamps:
+    _assert("133503840000000000", IdFormat::Filetime, "Windows FILETIME", "100 ns intervals since 1601-01-01");
+    _assert("638396640000000000", IdFormat::DotnetTicks, ".NET ticks", "100 ns intervals since 0001-01-01");
+    _assert("725760000.5", IdFormat::Cocoa, "Cocoa timestamp (Apple)", "Seconds since 2001-01-01 (Core Data)");
+    _assert("13348540800000000", IdFormat::Webkit, "WebKit timestamp (Chrome)", "Microseconds since 1601-01-01");
+    _assert("3913056000", IdFormat::Ntp, "NTP timestamp", "Seconds since 1900-01-01");
+    _assert("2295:86418", IdFormat::Gps, "GPS time", "Week 2295, second 86418 of the week");
+    _assert("45292.25", IdFormat::Excel, "Excel serial date", "Days since 1899-12-30 (1900 date system)");
+    _assert("2460310.5", IdFormat::JulianDay, "Julian Day", "Days since 4713-11-24 BC, noon");
+    _assert("20240101000000Z", IdFormat::Ldap, "LDAP timestamp", "Generalized Time");
+    _assert("133503840000000000", IdFormat::Ldap, "LDAP timestamp", "Active Directory, 100 ns intervals since 1601-01-01");
+    _assert("3786912000", IdFormat::Hfs, "HFS+ timestamp", "Seconds since 1904-01-01");
     // Other:
     _assert("01JCXSGZMZQQJ2M93WC0T8KT02", IdFormat::Ulid, "ULID", "-");
     // force a ULID to be treated as a Julid
@@ -273,11 +419,34 @@ fn test_force_format() {
     _assert("127.0.0.1", IdFormat::Ipv4, "IPv4 Address", "Loopback");
     _assert("10.0.0.1", IdFormat::Ipv4, "IPv4 Address", "Private");
     _assert("200.0.0.1", IdFormat::Ipv4, "IPv4 Address", "-");
+    _assert("0xC0A80101", IdFormat::Ipv4, "IPv4 Address", "Private");
+    _assert("127.1", IdFormat::Ipv4, "IPv4 Address", "Loopback");
     _assert("::1", IdFormat::Ipv6, "IPv6 Address", "Loopback");
     _assert("1::1", IdFormat::Ipv6, "IPv6 Address", "-");
     _assert("00:00:00:00:00:00", IdFormat::Mac, "MAC Address", "-");
     _assert("35-588906-014977-7", IdFormat::Imei, "IMEI", "-");
     _assert("355889060149777", IdFormat::Imei, "IMEI", "-");
+    _assert("3558890601497723", IdFormat::Imeisv, "IMEISV", "-");
+    _assert("268435456000009001", IdFormat::Meid, "MEID", "-");
+    _assert("8051F1AB", IdFormat::Esn, "ESN", "Pseudo-ESN (derived from a MEID)");
+    _assert("89 4410 0000 0000 0000 0", IdFormat::Iccid, "ICCID", "-");
+    _assert("8972012345678901239", IdFormat::Iccid, "ICCID", "-");
+    _assert("234990123456789", IdFormat::Imsi, "IMSI", "-");
+    _assert("378282246310005", IdFormat::Pan, "Payment Card Number", "American Express");
+    _assert("GB0002634946", IdFormat::Isin, "ISIN", "GB (United Kingdom)");
+    _assert("037833100", IdFormat::Cusip, "CUSIP", "North America");
+    _assert("B0YBKJ7", IdFormat::Sedol, "SEDOL", "-");
+    _assert("BBG000B9XRY4", IdFormat::Figi, "FIGI", "-");
+    _assert("HWUPKR0MPOU8FGXBT394", IdFormat::Lei, "LEI", "-");
+    _assert("DEUTDEF0XXX", IdFormat::Bic, "BIC (SWIFT code)", "Test BIC");
+    _assert("021000021", IdFormat::Aba, "ABA Routing Number", "Primary");
+    _assert("400515", IdFormat::SortCode, "UK Sort Code", "-");
+    _assert("03785955", IdFormat::Issn, "ISSN", "-");
+    _assert("M230671187", IdFormat::Ismn, "ISMN", "-");
+    _assert("GBAYE0601498", IdFormat::Isrc, "ISRC", "GB (United Kingdom)");
+    _assert("T0345246801", IdFormat::Iswc, "ISWC", "-");
+    _assert("0000000218250097", IdFormat::Isni, "ISNI", "ORCID iD");
+    _assert("doi:10.1000/182", IdFormat::Doi, "DOI", "-");
     // ISBN:
     _assert("978-0-553-38257-0", IdFormat::Isbn, "ISBN-13", "-");
     _assert("9780553382570", IdFormat::Isbn, "ISBN-13", "-");
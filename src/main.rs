@@ -2,6 +2,7 @@ use clap::Parser;
 use std::io;
 
 mod compare;
+mod country;
 mod formats;
 mod id_format;
 mod schema;
@@ -1,6 +1,9 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono_tz::Tz;
 use clap::Parser;
 use clap::ValueEnum;
 use serde::Serialize;
+use uuid::Uuid;
 
 #[derive(ValueEnum, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Output {
@@ -18,6 +21,10 @@ pub enum Output {
 pub enum IdFormat {
     /// UUID
     Uuid,
+    /// UUIDv7 with sub-millisecond precision in rand_a (e.g. PostgreSQL 18)
+    Uuid7SubMs,
+    /// UUIDv7 with a counter in rand_a
+    Uuid7Counter,
     /// ShortUUID
     Shortuuid,
     /// UUID as Integer
@@ -26,6 +33,14 @@ pub enum IdFormat {
     UuidB64,
     /// Uuid25
     Uuid25,
+    /// Microsoft GUID bytes (.NET Guid.ToByteArray, mixed-endian)
+    GuidBytes,
+    /// SQL Server NEWSEQUENTIALID
+    Newsequentialid,
+    /// COMB GUID (NHibernate, timestamp in the last 6 bytes)
+    Comb,
+    /// UUID as stored in databases (bytea, HEXTORAW, 0x literal, MySQL swap flag)
+    DatabaseUuid,
     /// ULID
     Ulid,
     /// Julid
@@ -98,6 +113,22 @@ pub enum IdFormat {
     Swhid,
     /// IBAN (International Bank Account Number)
     Iban,
+    /// Securities: ISIN (International Securities Identification Number)
+    Isin,
+    /// Securities: CUSIP (North America) and CINS
+    Cusip,
+    /// Securities: SEDOL (London Stock Exchange)
+    Sedol,
+    /// Securities: FIGI (Financial Instrument Global Identifier)
+    Figi,
+    /// Securities: LEI (Legal Entity Identifier)
+    Lei,
+    /// Banking: BIC (SWIFT code)
+    Bic,
+    /// Banking: ABA routing number (United States)
+    Aba,
+    /// Banking: Sort code (United Kingdom)
+    SortCode,
     /// Commerce Barcode (EAN/UPC/GTIN)
     Commerce,
     /// VIN (Vehicle Identification Number)
@@ -126,6 +157,26 @@ pub enum IdFormat {
     SfFlakeid,
     /// Snowflake: Simpleflake
     SfSimpleflake,
+    /// Snowflake: Sony v2 (see --time-unit)
+    SfSonyV2,
+    /// Snowflake: Baidu UidGenerator
+    SfBaidu,
+    /// Snowflake: Meituan Leaf
+    SfLeaf,
+    /// Snowflake: Yitter IdGenerator
+    SfYitter,
+    /// Snowflake: TikTok
+    SfTiktok,
+    /// Misskey: aid
+    MisskeyAid,
+    /// Misskey: aidx
+    MisskeyAidx,
+    /// Misskey: meid
+    MisskeyMeid,
+    /// Misskey: meidg
+    MisskeyMeidg,
+    /// Misskey: ObjectId
+    MisskeyObjectid,
     /// Unix timestamp: Auto-detect
     Unix,
     /// Unix timestamp: Seconds
@@ -136,6 +187,26 @@ pub enum IdFormat {
     UnixUs,
     /// Unix timestamp: Nanoseconds
     UnixNs,
+    /// Epoch timestamp: Windows FILETIME (100 ns since 1601)
+    Filetime,
+    /// Epoch timestamp: .NET DateTime ticks (100 ns since 0001)
+    DotnetTicks,
+    /// Epoch timestamp: Apple Cocoa / Core Data (seconds since 2001)
+    Cocoa,
+    /// Epoch timestamp: WebKit / Chrome (microseconds since 1601)
+    Webkit,
+    /// Epoch timestamp: NTP (seconds since 1900)
+    Ntp,
+    /// Epoch timestamp: GPS time (week:seconds or seconds since 1980)
+    Gps,
+    /// Epoch timestamp: Excel serial date (1900 date system)
+    Excel,
+    /// Epoch timestamp: Julian Day
+    JulianDay,
+    /// Epoch timestamp: LDAP Generalized Time / Active Directory
+    Ldap,
+    /// Epoch timestamp: HFS+ (seconds since 1904)
+    Hfs,
     /// Hex-encoded Hash
     Hash,
     /// IPFS Address (CID, IPNS)
@@ -148,12 +219,105 @@ pub enum IdFormat {
     Mac,
     /// Network: IMEI
     Imei,
+    /// Network: IMEISV (IMEI with Software Version Number)
+    Imeisv,
+    /// Network: MEID (CDMA, hex or decimal)
+    Meid,
+    /// Network: ESN (CDMA, hex or decimal)
+    Esn,
+    /// Network: ICCID (SIM card number)
+    Iccid,
+    /// Network: IMSI (SIM subscriber identity)
+    Imsi,
+    /// Payment card number (PAN), masked unless --reveal
+    Pan,
     /// ISBN
     Isbn,
+    /// Publishing: ISSN (serials)
+    Issn,
+    /// Publishing: ISMN (printed music)
+    Ismn,
+    /// Publishing: ISRC (sound recordings)
+    Isrc,
+    /// Publishing: ISWC (musical works)
+    Iswc,
+    /// Publishing: ISNI and ORCID iD
+    Isni,
+    /// Publishing: DOI (Digital Object Identifier)
+    Doi,
     /// Geo: H3 Grid System
     H3,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TimeZoneArg {
+    Local,
+    Named(Tz),
+    Fixed(FixedOffset),
+}
+
+pub fn parse_time_zone(value: &str) -> Result<TimeZoneArg, String> {
+    if value.eq_ignore_ascii_case("local") {
+        return Ok(TimeZoneArg::Local);
+    }
+    if let Ok(tz) = value.parse::<Tz>() {
+        return Ok(TimeZoneArg::Named(tz));
+    }
+    let sign = match value.chars().next() {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => return Err(format!("unknown time zone \"{value}\"; use \"local\", an IANA name or an offset like +05:30")),
+    };
+    let digits: String = value.chars().skip(1).filter(|c| *c != ':').collect();
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok(), Some(0)),
+        4 => (digits.get(..2).and_then(|v| v.parse::<i32>().ok()), digits.get(2..).and_then(|v| v.parse::<i32>().ok())),
+        _ => (None, None),
+    };
+    match (hours, minutes) {
+        (Some(hours), Some(minutes)) if minutes < 60 => FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(TimeZoneArg::Fixed)
+            .ok_or(format!("offset out of range: {value}")),
+        _ => Err(format!("invalid offset \"{value}\"; use a format like +05:30")),
+    }
+}
+
+/// Parses a date as RFC 3339, "YYYY-MM-DDTHH:MM:SS" or "YYYY-MM-DD" (the last two in UTC).
+pub fn parse_date(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S") {
+        return Ok(datetime.and_utc());
+    }
+    match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        Ok(date) => Ok(date.and_time(NaiveTime::MIN).and_utc()),
+        Err(_) => Err(format!("invalid date \"{value}\"; use a format like 2024-05-01 or 2024-05-01T12:00:00Z")),
+    }
+}
+
+/// Parses a UUID namespace: "dns", "url", "oid", "x500" or a custom UUID.
+pub fn parse_namespace(value: &str) -> Result<Uuid, String> {
+    match value.to_lowercase().as_str() {
+        "dns" => Ok(Uuid::NAMESPACE_DNS),
+        "url" => Ok(Uuid::NAMESPACE_URL),
+        "oid" => Ok(Uuid::NAMESPACE_OID),
+        "x500" => Ok(Uuid::NAMESPACE_X500),
+        _ => Uuid::try_parse(value).map_err(|_| format!("invalid namespace \"{value}\"; use dns, url, oid, x500 or a UUID")),
+    }
+}
+
+/// Candidate names read from a file, one per line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NameList(pub Vec<String>);
+
+pub fn read_names(path: &str) -> Result<NameList, String> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(NameList(content.lines().map(|line| line.trim_end_matches('\r').to_string()).filter(|line| !line.is_empty()).collect())),
+        Err(error) => Err(format!("cannot read \"{path}\": {error}")),
+    }
+}
+
 /// Shows debug information about complex ID.
 #[derive(Parser, Debug, Clone)]
 #[command(version, about, long_about = None)]
@@ -193,6 +357,42 @@ pub struct Args {
     /// Override epoch (seconds since 1970-01-01 UTC) for time-based IDs
     #[arg(long)]
     pub epoch: Option<u64>,
+
+    /// Time unit in milliseconds for Sony v2 Snowflakes (default: 10)
+    #[arg(long)]
+    pub time_unit: Option<u64>,
+
+    /// Show dates in a time zone: "local", IANA name (e.g. "Europe/Berlin") or offset (e.g. "+05:30")
+    #[arg(long, value_parser = parse_time_zone)]
+    pub tz: Option<TimeZoneArg>,
+
+    /// Prefer the interpretation whose date is closest to this one (e.g. "2024-05-01")
+    #[arg(long, value_parser = parse_date, conflicts_with = "between")]
+    pub near: Option<DateTime<Utc>>,
+
+    /// Prefer the interpretation whose date falls between these two
+    #[arg(long, num_args = 2, value_names = ["START", "END"], value_parser = parse_date)]
+    pub between: Option<Vec<DateTime<Utc>>>,
+
+    /// Namespace to verify UUID v3/v5 against: "dns", "url", "oid", "x500" or a UUID
+    #[arg(long, value_parser = parse_namespace, requires = "name")]
+    pub namespace: Option<Uuid>,
+
+    /// Name to verify UUID v3/v5 against (requires --namespace)
+    #[arg(long, requires = "namespace")]
+    pub name: Option<String>,
+
+    /// Try every line of this file as the name of a UUID v3/v5
+    #[arg(long, value_name = "FILE", value_parser = read_names, conflicts_with = "name")]
+    pub names_from: Option<NameList>,
+
+    /// Show payment card numbers in full instead of masking them
+    #[arg(long)]
+    pub reveal: bool,
+
+    /// Time to judge how recent an ID is against, instead of the system clock (for tests).
+    #[arg(skip)]
+    pub now: Option<DateTime<Utc>>,
 }
 
 impl Default for Args {
@@ -207,6 +407,15 @@ impl Default for Args {
             relative: false,
             salt: None,
             epoch: None,
+            time_unit: None,
+            tz: None,
+            near: None,
+            between: None,
+            namespace: None,
+            name: None,
+            names_from: None,
+            reveal: false,
+            now: None,
         }
     }
 }
@@ -216,6 +425,7 @@ pub struct TimestampComparable {
     pub timestamp: f64,   // For sorting.
     pub datetime: String, // To show.
     pub name: String,     // To show: ID Type and Version
+    pub best: bool,       // Closest to --near or --between.
 }
 
 #[allow(dead_code)]
@@ -230,6 +440,8 @@ pub struct IDInfo {
     pub size: u16,
     pub entropy: u16,
     pub datetime: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datetime_tz: Option<String>,
     pub timestamp: Option<String>,
     pub relative_time: Option<String>,
     pub sequence: Option<u128>,
@@ -256,3 +468,36 @@ IDInfo.color_map codes:
  - 6: blue (sequence)
  - 7: orange (node 3)
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_zone() {
+        assert_eq!(parse_time_zone("local"), Ok(TimeZoneArg::Local));
+        assert_eq!(parse_time_zone("Europe/Berlin"), Ok(TimeZoneArg::Named(chrono_tz::Europe::Berlin)));
+        assert_eq!(parse_time_zone("+05:30"), Ok(TimeZoneArg::Fixed(FixedOffset::east_opt(19800).unwrap())));
+        assert_eq!(parse_time_zone("-0800"), Ok(TimeZoneArg::Fixed(FixedOffset::west_opt(28800).unwrap())));
+        assert_eq!(parse_time_zone("+02"), Ok(TimeZoneArg::Fixed(FixedOffset::east_opt(7200).unwrap())));
+        assert!(parse_time_zone("Mars/Olympus").is_err());
+        assert!(parse_time_zone("+05:75").is_err());
+    }
+
+    #[test]
+    fn test_parse_namespace() {
+        assert_eq!(parse_namespace("dns"), Ok(Uuid::NAMESPACE_DNS));
+        assert_eq!(parse_namespace("X500"), Ok(Uuid::NAMESPACE_X500));
+        assert_eq!(parse_namespace("6ba7b811-9dad-11d1-80b4-00c04fd430c8"), Ok(Uuid::NAMESPACE_URL));
+        assert!(parse_namespace("isbn").is_err());
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("2024-05-01").unwrap().to_rfc3339(), "2024-05-01T00:00:00+00:00");
+        assert_eq!(parse_date("2024-05-01T12:30:00").unwrap().to_rfc3339(), "2024-05-01T12:30:00+00:00");
+        assert_eq!(parse_date("2024-05-01T12:30:00+02:00").unwrap().to_rfc3339(), "2024-05-01T10:30:00+00:00");
+        assert!(parse_date("2024-13-01").is_err());
+        assert!(parse_date("May 1st").is_err());
+    }
+}
@@ -1,12 +1,17 @@
-use chrono::{DateTime, SecondsFormat, Utc};
+use chrono::{DateTime, Local, SecondsFormat, Utc};
 use std::fmt::Write;
 
-use crate::schema::Args;
+use crate::schema::{Args, TimeZoneArg};
 
 pub fn epoch_ms(args: &Args, default: u64) -> u64 {
     args.epoch.map_or(default, |s| s.saturating_mul(1000))
 }
 
+/// The current time, unless `Args::now` pins it.
+pub fn now(args: &Args) -> DateTime<Utc> {
+    args.now.unwrap_or_else(Utc::now)
+}
+
 pub fn bits64(value: u64, offset: u8, length: u8) -> u64 {
     value << offset >> (64 - length)
 }
@@ -28,18 +33,76 @@ pub fn milliseconds_to_seconds_and_iso8601(ms: u64, epoch: u64) -> (String, Stri
     }
 }
 
-pub fn nanoseconds_to_iso8601(ns: u64) -> (String, String) {
-    let secs = (ns / 1_000_000_000) as i64;
-    let nanos = (ns % 1_000_000_000) as u32;
-    let dt: DateTime<Utc> = DateTime::from_timestamp(secs, nanos).unwrap();
-    let datetime = dt.to_rfc3339_opts(SecondsFormat::Nanos, true);
-    (format!("{}.{:09}", secs, nanos), datetime)
+/// Converts signed nanoseconds since 1970 to a timestamp with 9 decimals and an RFC 3339 datetime.
+pub fn unix_nanos_to_iso8601(ns: i128) -> (String, String) {
+    let sign = if ns < 0 { "-" } else { "" };
+    let timestamp = format!("{}{}.{:09}", sign, ns.unsigned_abs() / 1_000_000_000, ns.unsigned_abs() % 1_000_000_000);
+    let datetime = match (i64::try_from(ns.div_euclid(1_000_000_000)), u32::try_from(ns.rem_euclid(1_000_000_000))) {
+        (Ok(secs), Ok(nanos)) => match DateTime::from_timestamp(secs, nanos) {
+            Some(dt) => dt.to_rfc3339_opts(SecondsFormat::Nanos, true),
+            None => "Invalid".to_string(),
+        },
+        _ => "Invalid".to_string(),
+    };
+    (timestamp, datetime)
+}
+
+/// Parses a decimal number like "-123.456" as an integer scaled by `10^scale`, truncating extra digits.
+pub fn parse_scaled_decimal(text: &str, scale: u32) -> Option<i128> {
+    let (negative, unsigned) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if int_part.is_empty() || !int_part.chars().all(|c| c.is_ascii_digit()) || !frac_part.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let frac_digits: String = frac_part.chars().chain(std::iter::repeat('0')).take(scale as usize).collect();
+    let int_value = int_part.parse::<i128>().ok()?.checked_mul(10_i128.checked_pow(scale)?)?;
+    let value = int_value.checked_add(if frac_digits.is_empty() { 0 } else { frac_digits.parse::<i128>().ok()? })?;
+    Some(if negative { -value } else { value })
+}
+
+/// Renders an RFC 3339 datetime in another time zone, keeping its sub-second precision.
+pub fn localize_datetime(datetime: &str, tz: &TimeZoneArg) -> String {
+    let Ok(parsed) = DateTime::parse_from_rfc3339(datetime) else {
+        return datetime.to_string();
+    };
+    let fraction_digits = match datetime.find('.') {
+        Some(dot) => datetime.chars().skip(dot + 1).take_while(|c| c.is_ascii_digit()).count(),
+        None => 0,
+    };
+    let seconds_format = match fraction_digits {
+        0 => SecondsFormat::Secs,
+        1..=3 => SecondsFormat::Millis,
+        4..=6 => SecondsFormat::Micros,
+        _ => SecondsFormat::Nanos,
+    };
+    match tz {
+        TimeZoneArg::Local => parsed.with_timezone(&Local).to_rfc3339_opts(seconds_format, false),
+        TimeZoneArg::Named(zone) => parsed.with_timezone(zone).to_rfc3339_opts(seconds_format, false),
+        TimeZoneArg::Fixed(offset) => parsed.with_timezone(offset).to_rfc3339_opts(seconds_format, false),
+    }
 }
 
 pub fn repeat_char(c: char, n: usize) -> String {
     std::iter::repeat_n(c, n).collect()
 }
 
+/// Luhn (mod N) check digit of a string of digits in the given radix: 10 for card numbers, 16 for MEIDs.
+pub fn luhn_check_digit(digits: &str, radix: u32) -> Option<u32> {
+    let mut sum = 0;
+    for (index, c) in digits.chars().rev().enumerate() {
+        let digit = c.to_digit(radix)?;
+        sum += match index % 2 {
+            0 if digit * 2 >= radix => digit * 2 - (radix - 1),
+            0 => digit * 2,
+            _ => digit,
+        };
+    }
+    Some((radix - sum % radix) % radix)
+}
+
 pub fn factor_size_hex_bits_color_from_text(text: &str) -> (u16, Option<String>, Option<String>, Option<String>) {
     if !text.is_ascii() {
         return (0, None, None, None);
@@ -83,6 +146,13 @@ mod bits64 {
         assert_eq!(bits64(u64::MAX, 32, 32), u32::MAX as u64);
     }
 
+    #[test]
+    fn test_luhn_check_digit() {
+        assert_eq!(luhn_check_digit("7992739871", 10), Some(3));
+        assert_eq!(luhn_check_digit("A0000000002329", 16), Some(9));
+        assert_eq!(luhn_check_digit("12a", 10), None);
+    }
+
     #[test]
     fn test_time_formats_unix_epoch() {
         let (ts, dt) = milliseconds_to_seconds_and_iso8601(1420070400000, 0);
@@ -132,19 +202,57 @@ mod bits64 {
     }
 
     #[test]
-    fn test_nanoseconds_to_iso8601() {
-        let (ts, dt) = nanoseconds_to_iso8601(1734971723000000000);
+    fn test_unix_nanos_to_iso8601_positive() {
+        let (ts, dt) = unix_nanos_to_iso8601(1734971723000000000);
         assert_eq!(ts, "1734971723.000000000");
         assert_eq!(dt, "2024-12-23T16:35:23.000000000Z");
     }
 
     #[test]
-    fn test_nanoseconds_to_iso8601_with_nanos() {
-        let (ts, dt) = nanoseconds_to_iso8601(1734971723123456789);
+    fn test_unix_nanos_to_iso8601_positive_with_nanos() {
+        let (ts, dt) = unix_nanos_to_iso8601(1734971723123456789);
         assert_eq!(ts, "1734971723.123456789");
         assert_eq!(dt, "2024-12-23T16:35:23.123456789Z");
     }
 
+    #[test]
+    fn test_unix_nanos_to_iso8601_negative() {
+        let (ts, dt) = unix_nanos_to_iso8601(-500_000_000);
+        assert_eq!(ts, "-0.500000000");
+        assert_eq!(dt, "1969-12-31T23:59:59.500000000Z");
+        let (_, dt) = unix_nanos_to_iso8601(-11_644_473_600_000_000_000);
+        assert_eq!(dt, "1601-01-01T00:00:00.000000000Z");
+    }
+
+    #[test]
+    fn test_unix_nanos_to_iso8601_invalid() {
+        let (_, dt) = unix_nanos_to_iso8601(i128::MAX);
+        assert_eq!(dt, "Invalid");
+    }
+
+    #[test]
+    fn test_parse_scaled_decimal() {
+        assert_eq!(parse_scaled_decimal("1700000000.123456", 9), Some(1700000000123456000));
+        assert_eq!(parse_scaled_decimal("-86400", 3), Some(-86400000));
+        assert_eq!(parse_scaled_decimal("0.1234", 2), Some(12));
+        assert_eq!(parse_scaled_decimal("12", 0), Some(12));
+        assert_eq!(parse_scaled_decimal("1.", 1), Some(10));
+        assert_eq!(parse_scaled_decimal(".5", 1), None);
+        assert_eq!(parse_scaled_decimal("1e5", 1), None);
+        assert_eq!(parse_scaled_decimal("1.2.3", 1), None);
+        assert_eq!(parse_scaled_decimal("", 1), None);
+    }
+
+    #[test]
+    fn test_localize_datetime() {
+        let berlin = TimeZoneArg::Named(chrono_tz::Europe::Berlin);
+        assert_eq!(localize_datetime("2025-01-01T00:00:00.000Z", &berlin), "2025-01-01T01:00:00.000+01:00");
+        assert_eq!(localize_datetime("2025-07-01T00:00:00Z", &berlin), "2025-07-01T02:00:00+02:00");
+        let india = TimeZoneArg::Fixed(chrono::FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap());
+        assert_eq!(localize_datetime("2024-12-23T16:35:23.123456789Z", &india), "2024-12-23T22:05:23.123456789+05:30");
+        assert_eq!(localize_datetime("Invalid", &india), "Invalid");
+    }
+
     #[test]
     fn test_repeat_char() {
         assert_eq!(repeat_char('3', 5), "33333");
@@ -63,24 +63,55 @@ fn test_compare() {
     let output = String::from_utf8_lossy(&command.stdout);
     let expected = "\
 Date/times of the valid IDs parsed as:
+- 1907-05-19T19:10:43.653Z NTP timestamp: 64-bit fixed point since 1900-01-01
 - 1977-05-18T19:10:43.000Z Snowflake: Frostflake
+- 1977-05-18T19:10:43.000Z Snowflake: TikTok
 - 1977-07-22T11:22:59.101Z Snowflake: LinkedIn
 - 1977-07-22T11:22:59.101Z Snowflake: Flake ID
 - 2001-09-09T01:46:40.000Z Unix timestamp: Assuming nanoseconds
 - 2003-10-11T22:41:29.550Z Snowflake: Simpleflake
 - 2015-06-04T14:48:31.271Z Thread ID (Meta Threads)
 - 2015-06-04T14:48:31.271Z Snowflake: Instagram
+- 2017-04-21T12:23:50.000Z Snowflake: Baidu UidGenerator
 - 2018-05-25T13:05:53.758Z Snowflake: Twitter
+- 2018-05-25T13:05:53.758Z Snowflake: Meituan Leaf
 - 2022-07-22T11:22:59.101Z Snowflake: Discord
 - 2022-07-22T11:22:59.101Z Snowflake: Spaceflake
 - 2027-07-22T11:22:59.101Z TSID
 - 2031-07-22T11:22:59.101Z SnowID
 - 2033-07-21T16:27:27.750Z Snowflake: Sony
-- 2453-07-13T08:31:02.500Z Snowflake: Mastodon";
+- 2043-11-21T16:27:27.750Z Snowflake: Sony v2 (10 ms time unit)
+- 2453-07-13T08:31:02.500Z Snowflake: Mastodon
+- 3169-11-16T09:46:40.000Z .NET ticks: 100 ns intervals since 0001-01-01
+- 4769-11-16T09:46:40.000Z Windows FILETIME: 100 ns intervals since 1601-01-01
+- 9756-08-23T18:36:42.020Z Snowflake: Yitter
+- +33289-09-27T01:46:40.000Z WebKit timestamp (Chrome): Microseconds since 1601-01-01";
     let actual: String = output.lines().filter(|line| !line.contains("--- Now ---")).collect::<Vec<_>>().join("\n");
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn test_compare_near() {
+    let command = Command::new(CLI).args(["-c", "--near", "2018-01-01", "1000000000000000000"]).output().unwrap();
+    let output = String::from_utf8_lossy(&command.stdout);
+    let best: Vec<&str> = output.lines().filter(|line| line.contains("(best match)")).collect();
+    assert_eq!(best, vec!["- 2018-05-25T13:05:53.758Z Snowflake: Twitter (best match)"]);
+}
+
+#[test]
+fn test_masked_pan_not_leaked() {
+    let command = Command::new(CLI).args(["-c", "4111111111111111"]).output().unwrap();
+    let output = String::from_utf8_lossy(&command.stdout);
+    assert_eq!(output.lines().filter(|line| line.starts_with("- ") && !line.contains("--- Now ---")).count(), 0, "{output}");
+    let command = Command::new(CLI).args(["-f", "unix", "4111111111111111"]).output().unwrap();
+    let output = String::from_utf8_lossy(&command.stdout);
+    assert_eq!(output, "Invalid ID for this format.\n");
+    let command = Command::new(CLI).args(["-f", "uuid-int", "-o", "json", "4111111111111111"]).output().unwrap();
+    assert!(!String::from_utf8_lossy(&command.stdout).contains("4111111111111111"));
+    let command = Command::new(CLI).args(["-f", "unix", "--reveal", "4111111111111111"]).output().unwrap();
+    assert!(String::from_utf8_lossy(&command.stdout).contains("4111111111111111"));
+}
+
 #[test]
 fn test_unknown_id_type() {
     let command = Command::new(CLI).arg("WHAT%").output().unwrap();